@@ -105,5 +105,44 @@ fn compare_path(bencher: &mut Bencher) {
     });
 }
 
+fn names(count: usize) -> Vec<String> {
+    let mut seed: u32 = 1;
+
+    (0..count)
+        .map(|_| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+
+            let n = seed >> 8;
+
+            format!("IMG_{:04}-{}-第{}章.jpg", n % 10000, n % 97, n % 13)
+        })
+        .collect()
+}
+
+fn sort_str_slice(bencher: &mut Bencher) {
+    let names = names(100_000);
+
+    bencher.iter(|| {
+        let mut array = names.clone();
+
+        alphanumeric_sort::sort_str_slice(&mut array);
+
+        array
+    });
+}
+
+fn sort_str_slice_cached(bencher: &mut Bencher) {
+    let names = names(100_000);
+
+    bencher.iter(|| {
+        let mut array = names.clone();
+
+        alphanumeric_sort::sort_str_slice_cached(&mut array);
+
+        array
+    });
+}
+
 benchmark_group!(sort_path_compare_path, sort_slice_by_path_key, sort_path_slice, compare_path);
-benchmark_main!(sort_path_compare_path);
+benchmark_group!(sort_str_cached, sort_str_slice, sort_str_slice_cached);
+benchmark_main!(sort_path_compare_path, sort_str_cached);
@@ -0,0 +1,366 @@
+use alloc::{borrow::ToOwned, string::String};
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::{self, Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+    ops::Deref,
+};
+
+use crate::compare_str;
+
+/// A `str` whose `Ord` is `compare_str`.
+///
+/// `compare_str` only considers two strings equal if they are identical (e.g. `"01"` and `"1"` are not), so `Eq` and `Hash` are the same as those of `str`.
+///
+/// ```rust
+/// use alphanumeric_sort::AlphanumericStr;
+///
+/// assert!(AlphanumericStr::new("shot-2") < AlphanumericStr::new("shot-10"));
+/// assert_ne!(AlphanumericStr::new("01"), AlphanumericStr::new("1"));
+/// ```
+#[repr(transparent)]
+pub struct AlphanumericStr(str);
+
+impl AlphanumericStr {
+    /// Wrap a `str`.
+    #[inline]
+    pub fn new<S: AsRef<str> + ?Sized>(s: &S) -> &AlphanumericStr {
+        let s: &str = s.as_ref();
+
+        // Safety: `AlphanumericStr` is a `repr(transparent)` wrapper of `str`
+        unsafe { &*(s as *const str as *const AlphanumericStr) }
+    }
+
+    /// Get the wrapped `str`.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq for AlphanumericStr {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for AlphanumericStr {}
+
+impl PartialOrd for AlphanumericStr {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AlphanumericStr {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_str(&self.0, &other.0)
+    }
+}
+
+impl Hash for AlphanumericStr {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl Debug for AlphanumericStr {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl Display for AlphanumericStr {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl AsRef<str> for AlphanumericStr {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<AlphanumericStr> for AlphanumericStr {
+    #[inline]
+    fn as_ref(&self) -> &AlphanumericStr {
+        self
+    }
+}
+
+impl<'a> From<&'a str> for &'a AlphanumericStr {
+    #[inline]
+    fn from(s: &'a str) -> Self {
+        AlphanumericStr::new(s)
+    }
+}
+
+impl Default for &AlphanumericStr {
+    #[inline]
+    fn default() -> Self {
+        AlphanumericStr::new("")
+    }
+}
+
+impl ToOwned for AlphanumericStr {
+    type Owned = AlphanumericString;
+
+    #[inline]
+    fn to_owned(&self) -> AlphanumericString {
+        AlphanumericString(String::from(&self.0))
+    }
+}
+
+/// An owned `String` whose `Ord` is `compare_str`, which is the owned counterpart of [`AlphanumericStr`].
+///
+/// It implements `Borrow<AlphanumericStr>`, so a `BTreeMap` or a `HashMap` whose keys are `AlphanumericString` can be looked up by `&AlphanumericStr`. It does not implement `Borrow<str>`, because `Borrow` requires `Ord` to be the same, and `compare_str` is not the `Ord` of `str`.
+///
+/// ```rust
+/// use std::collections::BTreeSet;
+///
+/// use alphanumeric_sort::{AlphanumericStr, AlphanumericString};
+///
+/// let set: BTreeSet<AlphanumericString> = ["shot-10", "shot-2", "shot-1"]
+///     .iter()
+///     .map(|s| AlphanumericString::from(*s))
+///     .collect();
+///
+/// assert!(set.contains(AlphanumericStr::new("shot-2")));
+/// assert_eq!(
+///     ["shot-1", "shot-2", "shot-10"],
+///     set.iter().map(|s| s.as_str()).collect::<Vec<_>>().as_slice()
+/// );
+/// ```
+#[derive(Clone, Default)]
+pub struct AlphanumericString(String);
+
+impl AlphanumericString {
+    /// Wrap a `String`.
+    #[inline]
+    pub fn new<S: Into<String>>(s: S) -> Self {
+        AlphanumericString(s.into())
+    }
+
+    /// Get the wrapped `str`.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Get the wrapped `String`.
+    #[inline]
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for AlphanumericString {
+    type Target = AlphanumericStr;
+
+    #[inline]
+    fn deref(&self) -> &AlphanumericStr {
+        AlphanumericStr::new(&self.0)
+    }
+}
+
+impl Borrow<AlphanumericStr> for AlphanumericString {
+    #[inline]
+    fn borrow(&self) -> &AlphanumericStr {
+        self
+    }
+}
+
+impl PartialEq for AlphanumericString {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for AlphanumericString {}
+
+impl PartialOrd for AlphanumericString {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AlphanumericString {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_str(&self.0, &other.0)
+    }
+}
+
+impl Hash for AlphanumericString {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl Debug for AlphanumericString {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl Display for AlphanumericString {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl AsRef<str> for AlphanumericString {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<AlphanumericStr> for AlphanumericString {
+    #[inline]
+    fn as_ref(&self) -> &AlphanumericStr {
+        self
+    }
+}
+
+impl From<String> for AlphanumericString {
+    #[inline]
+    fn from(s: String) -> Self {
+        AlphanumericString(s)
+    }
+}
+
+impl From<&str> for AlphanumericString {
+    #[inline]
+    fn from(s: &str) -> Self {
+        AlphanumericString(String::from(s))
+    }
+}
+
+impl From<&AlphanumericStr> for AlphanumericString {
+    #[inline]
+    fn from(s: &AlphanumericStr) -> Self {
+        s.to_owned()
+    }
+}
+
+impl From<AlphanumericString> for String {
+    #[inline]
+    fn from(s: AlphanumericString) -> Self {
+        s.0
+    }
+}
+
+/// A wrapper of any `AsRef<str>` value whose `Ord` is `compare_str` on the wrapped string.
+///
+/// As with [`AlphanumericStr`], `Eq` and `Hash` are the same as those of the wrapped `str`, so `Alphanumeric(x)` fits `BTreeMap`, `BinaryHeap` and `binary_search` as well as `HashMap`.
+///
+/// ```rust
+/// use std::collections::BinaryHeap;
+///
+/// use alphanumeric_sort::Alphanumeric;
+///
+/// let mut heap: BinaryHeap<_> =
+///     ["shot-2", "shot-10", "shot-1"].iter().map(Alphanumeric).collect();
+///
+/// assert_eq!(Some(Alphanumeric(&"shot-10")), heap.pop());
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct Alphanumeric<T>(pub T);
+
+impl<T> Alphanumeric<T> {
+    /// Get the wrapped value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: AsRef<str>> Alphanumeric<T> {
+    /// Get the wrapped value as an `AlphanumericStr`.
+    #[inline]
+    pub fn as_alphanumeric_str(&self) -> &AlphanumericStr {
+        AlphanumericStr::new(self.0.as_ref())
+    }
+}
+
+impl<T> Deref for Alphanumeric<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: AsRef<str>> PartialEq for Alphanumeric<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_ref() == other.0.as_ref()
+    }
+}
+
+impl<T: AsRef<str>> Eq for Alphanumeric<T> {}
+
+impl<T: AsRef<str>> PartialOrd for Alphanumeric<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: AsRef<str>> Ord for Alphanumeric<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_str(self.0.as_ref(), other.0.as_ref())
+    }
+}
+
+impl<T: AsRef<str>> Hash for Alphanumeric<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_ref().hash(state)
+    }
+}
+
+impl<T: Debug> Debug for Alphanumeric<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Alphanumeric").field(&self.0).finish()
+    }
+}
+
+impl<T: Display> Display for Alphanumeric<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<T: AsRef<str>> AsRef<str> for Alphanumeric<T> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.0.as_ref()
+    }
+}
+
+impl<T> From<T> for Alphanumeric<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Alphanumeric(value)
+    }
+}
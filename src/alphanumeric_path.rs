@@ -0,0 +1,293 @@
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    ffi::OsStr,
+    fmt::{self, Debug, Formatter},
+    hash::{Hash, Hasher},
+    ops::Deref,
+    path::{Path, PathBuf},
+};
+
+use crate::compare_path;
+
+/// A `Path` whose `Ord` is `compare_path`.
+///
+/// `compare_path` only considers two paths equal if their `OsStr` representations are identical, so `Eq` and `Hash` are the same as those of `OsStr`. Note that they are stricter than those of `Path`, which considers `"a/b"` and `"a//b"` equal.
+///
+/// ```rust
+/// use std::path::Path;
+///
+/// use alphanumeric_sort::AlphanumericPath;
+///
+/// assert!(
+///     AlphanumericPath::new("shot-2")
+///         < AlphanumericPath::new(Path::new("shot-10"))
+/// );
+/// ```
+#[repr(transparent)]
+pub struct AlphanumericPath(Path);
+
+impl AlphanumericPath {
+    /// Wrap a `Path`. Nothing is copied, so this is also the way to look up a collection of `AlphanumericPathBuf` by a `&Path`.
+    ///
+    /// ```rust
+    /// use std::{collections::BTreeSet, path::Path};
+    ///
+    /// use alphanumeric_sort::{AlphanumericPath, AlphanumericPathBuf};
+    ///
+    /// let names: BTreeSet<AlphanumericPathBuf> = ["shot-2", "shot-10"]
+    ///     .into_iter()
+    ///     .map(AlphanumericPathBuf::from)
+    ///     .collect();
+    ///
+    /// let path: &Path = Path::new("shot-10");
+    ///
+    /// assert!(names.contains(AlphanumericPath::new(path)));
+    /// ```
+    #[inline]
+    pub fn new<P: AsRef<Path> + ?Sized>(p: &P) -> &AlphanumericPath {
+        let p: &Path = p.as_ref();
+
+        // Safety: `AlphanumericPath` is a `repr(transparent)` wrapper of `Path`
+        unsafe { &*(p as *const Path as *const AlphanumericPath) }
+    }
+
+    /// Get the wrapped `Path`.
+    #[inline]
+    pub fn as_path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Deref for AlphanumericPath {
+    type Target = Path;
+
+    #[inline]
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl PartialEq for AlphanumericPath {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_os_str() == other.0.as_os_str()
+    }
+}
+
+impl Eq for AlphanumericPath {}
+
+impl PartialOrd for AlphanumericPath {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AlphanumericPath {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_path(&self.0, &other.0)
+    }
+}
+
+impl Hash for AlphanumericPath {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_os_str().hash(state)
+    }
+}
+
+impl Debug for AlphanumericPath {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl AsRef<Path> for AlphanumericPath {
+    #[inline]
+    fn as_ref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl AsRef<OsStr> for AlphanumericPath {
+    #[inline]
+    fn as_ref(&self) -> &OsStr {
+        self.0.as_os_str()
+    }
+}
+
+impl AsRef<AlphanumericPath> for AlphanumericPath {
+    #[inline]
+    fn as_ref(&self) -> &AlphanumericPath {
+        self
+    }
+}
+
+impl<'a> From<&'a Path> for &'a AlphanumericPath {
+    #[inline]
+    fn from(p: &'a Path) -> Self {
+        AlphanumericPath::new(p)
+    }
+}
+
+impl ToOwned for AlphanumericPath {
+    type Owned = AlphanumericPathBuf;
+
+    #[inline]
+    fn to_owned(&self) -> AlphanumericPathBuf {
+        AlphanumericPathBuf(self.0.to_path_buf())
+    }
+}
+
+/// An owned `PathBuf` whose `Ord` is `compare_path`, which is the owned counterpart of [`AlphanumericPath`].
+///
+/// It implements `Borrow<AlphanumericPath>`, so a `BTreeMap` or a `HashMap` whose keys are `AlphanumericPathBuf` can be looked up by a plain `&Path` wrapped with `AlphanumericPath::new`, which costs nothing. It does not implement `Borrow<Path>`, because `Borrow` requires `Ord`, `Eq` and `Hash` to be the same, and those of `Path` are not.
+///
+/// ```rust
+/// use std::{collections::BTreeMap, path::Path};
+///
+/// use alphanumeric_sort::{AlphanumericPath, AlphanumericPathBuf};
+///
+/// let mut map = BTreeMap::new();
+///
+/// map.insert(AlphanumericPathBuf::from("shot-10"), 10);
+/// map.insert(AlphanumericPathBuf::from("shot-2"), 2);
+///
+/// assert_eq!(Some(&2), map.get(AlphanumericPath::new(Path::new("shot-2"))));
+/// assert_eq!(vec![&2, &10], map.values().collect::<Vec<_>>());
+/// ```
+#[derive(Clone, Default)]
+pub struct AlphanumericPathBuf(PathBuf);
+
+impl AlphanumericPathBuf {
+    /// Wrap a `PathBuf`.
+    #[inline]
+    pub fn new<P: Into<PathBuf>>(p: P) -> Self {
+        AlphanumericPathBuf(p.into())
+    }
+
+    /// Get the wrapped `Path`.
+    #[inline]
+    pub fn as_path(&self) -> &Path {
+        &self.0
+    }
+
+    /// Get the wrapped `PathBuf`.
+    #[inline]
+    pub fn into_path_buf(self) -> PathBuf {
+        self.0
+    }
+}
+
+impl Deref for AlphanumericPathBuf {
+    type Target = AlphanumericPath;
+
+    #[inline]
+    fn deref(&self) -> &AlphanumericPath {
+        AlphanumericPath::new(&self.0)
+    }
+}
+
+/// There is no `Borrow<Path>`, since `Path` has different `Eq` and `Hash`. Look up a `&Path` with `AlphanumericPath::new` instead.
+impl Borrow<AlphanumericPath> for AlphanumericPathBuf {
+    #[inline]
+    fn borrow(&self) -> &AlphanumericPath {
+        self
+    }
+}
+
+impl PartialEq for AlphanumericPathBuf {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_os_str() == other.0.as_os_str()
+    }
+}
+
+impl Eq for AlphanumericPathBuf {}
+
+impl PartialOrd for AlphanumericPathBuf {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AlphanumericPathBuf {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_path(&self.0, &other.0)
+    }
+}
+
+impl Hash for AlphanumericPathBuf {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_os_str().hash(state)
+    }
+}
+
+impl Debug for AlphanumericPathBuf {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl AsRef<Path> for AlphanumericPathBuf {
+    #[inline]
+    fn as_ref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl AsRef<OsStr> for AlphanumericPathBuf {
+    #[inline]
+    fn as_ref(&self) -> &OsStr {
+        self.0.as_os_str()
+    }
+}
+
+impl AsRef<AlphanumericPath> for AlphanumericPathBuf {
+    #[inline]
+    fn as_ref(&self) -> &AlphanumericPath {
+        self
+    }
+}
+
+impl From<PathBuf> for AlphanumericPathBuf {
+    #[inline]
+    fn from(p: PathBuf) -> Self {
+        AlphanumericPathBuf(p)
+    }
+}
+
+impl From<&Path> for AlphanumericPathBuf {
+    #[inline]
+    fn from(p: &Path) -> Self {
+        AlphanumericPathBuf(p.to_path_buf())
+    }
+}
+
+impl From<&str> for AlphanumericPathBuf {
+    #[inline]
+    fn from(s: &str) -> Self {
+        AlphanumericPathBuf(PathBuf::from(s))
+    }
+}
+
+impl From<&AlphanumericPath> for AlphanumericPathBuf {
+    #[inline]
+    fn from(p: &AlphanumericPath) -> Self {
+        p.to_owned()
+    }
+}
+
+impl From<AlphanumericPathBuf> for PathBuf {
+    #[inline]
+    fn from(p: AlphanumericPathBuf) -> Self {
+        p.0
+    }
+}
@@ -0,0 +1,260 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::{
+    compare_str, compare_units, ref_index_str_pairs_to_ref_indexes,
+    ref_index_str_pairs_to_ref_indexes_rev, ref_index_str_pairs_to_ref_indexes_rev_unstable,
+    ref_index_str_pairs_to_ref_indexes_unstable, sort_slice_ref_indexes,
+};
+
+/// Compare two byte strings.
+///
+/// Byte strings which are valid UTF-8 are compared in the same way as `compare_str`. Otherwise, every valid UTF-8 sequence is still compared as a character and ASCII digit runs are still compared by their numeric values, while a byte which is not a part of a character is greater than any character.
+///
+/// ```rust
+/// use core::cmp::Ordering;
+///
+/// assert_eq!(
+///     Ordering::Less,
+///     alphanumeric_sort::compare_bytes(b"shot-2", b"shot-10")
+/// );
+/// assert_eq!(
+///     Ordering::Less,
+///     alphanumeric_sort::compare_bytes(b"shot-2\xff", b"shot-10")
+/// );
+/// ```
+#[inline]
+pub fn compare_bytes<A: AsRef<[u8]>, B: AsRef<[u8]>>(a: A, b: B) -> Ordering {
+    let a = a.as_ref();
+    let b = b.as_ref();
+
+    match (core::str::from_utf8(a), core::str::from_utf8(b)) {
+        (Ok(sa), Ok(sb)) => compare_str(sa, sb),
+        _ => compare_bytes_fallback(a, b),
+    }
+}
+
+#[inline]
+fn compare_bytes_fallback(a: &[u8], b: &[u8]) -> Ordering {
+    compare_units(Utf8Units::new(a), Utf8Units::new(b))
+}
+
+// Byte-key sorting
+
+/// Sort a slice by a `[u8]` key, but may not preserve the order of equal elements.
+///
+/// The keys are compared in the same way as `compare_bytes`, so a key which is not valid UTF-8 does not change the order of the others.
+#[inline]
+pub fn sort_slice_unstable_by_bytes_key<A, T: ?Sized + AsRef<[u8]>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    f: F,
+) {
+    sort_slice_by_bytes_key_inner(
+        slice,
+        f,
+        ref_index_str_pairs_to_ref_indexes_unstable,
+        sort_slice_unstable_by_bytes_key_fallback,
+    )
+}
+
+/// Sort a slice by a `[u8]` key.
+///
+/// The keys are compared in the same way as `compare_bytes`, so a key which is not valid UTF-8 does not change the order of the others.
+#[inline]
+pub fn sort_slice_by_bytes_key<A, T: ?Sized + AsRef<[u8]>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    f: F,
+) {
+    sort_slice_by_bytes_key_inner(
+        slice,
+        f,
+        ref_index_str_pairs_to_ref_indexes,
+        sort_slice_by_bytes_key_fallback,
+    )
+}
+
+/// Reversely sort a slice by a `[u8]` key, but may not preserve the order of equal elements.
+///
+/// The keys are compared in the same way as `compare_bytes`, so a key which is not valid UTF-8 does not change the order of the others.
+#[inline]
+pub fn sort_slice_rev_unstable_by_bytes_key<A, T: ?Sized + AsRef<[u8]>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    f: F,
+) {
+    sort_slice_by_bytes_key_inner(
+        slice,
+        f,
+        ref_index_str_pairs_to_ref_indexes_rev_unstable,
+        sort_slice_rev_unstable_by_bytes_key_fallback,
+    )
+}
+
+/// Reversely sort a slice by a `[u8]` key.
+///
+/// The keys are compared in the same way as `compare_bytes`, so a key which is not valid UTF-8 does not change the order of the others.
+#[inline]
+pub fn sort_slice_rev_by_bytes_key<A, T: ?Sized + AsRef<[u8]>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    f: F,
+) {
+    sort_slice_by_bytes_key_inner(
+        slice,
+        f,
+        ref_index_str_pairs_to_ref_indexes_rev,
+        sort_slice_rev_by_bytes_key_fallback,
+    )
+}
+
+fn sort_slice_by_bytes_key_inner<A, T: ?Sized + AsRef<[u8]>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    mut f: F,
+    ref_index_str_pairs_to_ref_indexes: impl Fn(Vec<(usize, &str)>) -> Vec<usize>,
+    fallback: impl Fn(&mut [A], F),
+) {
+    let mut use_str = true;
+
+    let mut ref_index_str_pairs = Vec::with_capacity(slice.len());
+
+    for (i, p) in slice.iter().enumerate() {
+        let s = match core::str::from_utf8(f(p).as_ref()) {
+            Ok(s) => s,
+            Err(_) => {
+                use_str = false;
+                break;
+            },
+        };
+
+        ref_index_str_pairs.push((i, s));
+    }
+
+    if use_str {
+        let ref_indexes = ref_index_str_pairs_to_ref_indexes(ref_index_str_pairs);
+
+        sort_slice_ref_indexes(slice, ref_indexes);
+    } else {
+        fallback(slice, f);
+    }
+}
+
+#[inline]
+fn sort_slice_unstable_by_bytes_key_fallback<A, T: ?Sized + AsRef<[u8]>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    mut f: F,
+) {
+    slice.sort_unstable_by(|a, b| compare_bytes_fallback(f(a).as_ref(), f(b).as_ref()));
+}
+
+#[inline]
+fn sort_slice_by_bytes_key_fallback<A, T: ?Sized + AsRef<[u8]>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    mut f: F,
+) {
+    slice.sort_by(|a, b| compare_bytes_fallback(f(a).as_ref(), f(b).as_ref()));
+}
+
+#[inline]
+fn sort_slice_rev_unstable_by_bytes_key_fallback<A, T: ?Sized + AsRef<[u8]>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    mut f: F,
+) {
+    slice.sort_unstable_by(|a, b| compare_bytes_fallback(f(b).as_ref(), f(a).as_ref()));
+}
+
+#[inline]
+fn sort_slice_rev_by_bytes_key_fallback<A, T: ?Sized + AsRef<[u8]>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    mut f: F,
+) {
+    slice.sort_by(|a, b| compare_bytes_fallback(f(b).as_ref(), f(a).as_ref()));
+}
+
+// Direct byte slice sorting
+
+/// Sort a `[u8]` slice.
+///
+/// The items are compared in the same way as `compare_bytes`, so an item which is not valid UTF-8 does not change the order of the others.
+#[inline]
+pub fn sort_bytes_slice<S: AsRef<[u8]>>(slice: &mut [S]) {
+    sort_slice_unstable_by_bytes_key(slice, |e| e.as_ref())
+}
+
+/// Reversely sort a `[u8]` slice.
+///
+/// The items are compared in the same way as `compare_bytes`, so an item which is not valid UTF-8 does not change the order of the others.
+#[inline]
+pub fn sort_bytes_slice_rev<S: AsRef<[u8]>>(slice: &mut [S]) {
+    sort_slice_rev_unstable_by_bytes_key(slice, |e| e.as_ref())
+}
+
+/// The units of a byte string which is UTF-8 or almost UTF-8 (e.g. WTF-8).
+///
+/// A valid UTF-8 sequence is a Unicode scalar value. A WTF-8 sequence of a surrogate is the code point of the surrogate. Any other byte is `0x110000 + byte`, which is greater than every Unicode code point, so invalid sequences are ordered deterministically after all characters.
+pub(crate) struct Utf8Units<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Utf8Units<'a> {
+    #[inline]
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Utf8Units {
+            bytes,
+        }
+    }
+}
+
+impl<'a> Iterator for Utf8Units<'a> {
+    type Item = u32;
+
+    #[inline]
+    fn next(&mut self) -> Option<u32> {
+        let first = *self.bytes.first()?;
+
+        if first < 0x80 {
+            self.bytes = &self.bytes[1..];
+
+            return Some(first as u32);
+        }
+
+        let width = match first {
+            0xC2..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            0xF0..=0xF4 => 4,
+            _ => 0,
+        };
+
+        if width > 0 && self.bytes.len() >= width {
+            let sequence = &self.bytes[..width];
+
+            let unit = match core::str::from_utf8(sequence) {
+                Ok(s) => s.chars().next().map(u32::from),
+                Err(_) => decode_surrogate(sequence),
+            };
+
+            if let Some(unit) = unit {
+                self.bytes = &self.bytes[width..];
+
+                return Some(unit);
+            }
+        }
+
+        self.bytes = &self.bytes[1..];
+
+        Some(0x110000 + first as u32)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.bytes.len().div_ceil(4), Some(self.bytes.len()))
+    }
+}
+
+/// Decode a surrogate encoded like a 3-byte UTF-8 sequence (`ED A0..=BF 80..=BF`).
+#[inline]
+fn decode_surrogate(sequence: &[u8]) -> Option<u32> {
+    match *sequence {
+        [0xED, b2 @ 0xA0..=0xBF, b3 @ 0x80..=0xBF] => {
+            Some(0xD000 | ((b2 as u32 & 0x3F) << 6) | (b3 as u32 & 0x3F))
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,121 @@
+use core::iter::FusedIterator;
+
+/// A part of a string split by [`chunks`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Chunk<'a> {
+    /// A maximal run of characters which are not ASCII digits. Its characters are compared one by one.
+    Text(&'a str),
+    /// A maximal run of ASCII digits, which is compared by its numeric value.
+    Number {
+        /// All the digits, including the leading zeros.
+        digits:        &'a str,
+        /// The digits without the leading zeros, which is empty if the value is zero.
+        value_digits:  &'a str,
+        /// The count of the leading zeros. If the value is zero, all the digits are counted. When two numbers have the same value, the one with more leading zeros is greater, if nothing else differs.
+        leading_zeros: usize,
+    },
+}
+
+impl<'a> Chunk<'a> {
+    /// Get the original part of the string.
+    #[inline]
+    pub fn as_str(&self) -> &'a str {
+        match *self {
+            Chunk::Text(s) => s,
+            Chunk::Number {
+                digits, ..
+            } => digits,
+        }
+    }
+
+    /// Whether this is a `Chunk::Number`.
+    #[inline]
+    pub fn is_number(&self) -> bool {
+        matches!(self, Chunk::Number { .. })
+    }
+}
+
+/// Split a string into text and number chunks, in the same way as `compare_str` does.
+///
+/// Concatenating the chunks gives the original string. Text and number chunks alternate, so two adjacent chunks are never of the same kind.
+///
+/// ```rust
+/// use alphanumeric_sort::Chunk;
+///
+/// let chunks: Vec<Chunk> =
+///     alphanumeric_sort::chunks("shot-007.png").collect();
+///
+/// assert_eq!(
+///     vec![
+///         Chunk::Text("shot-"),
+///         Chunk::Number {
+///             digits:        "007",
+///             value_digits:  "7",
+///             leading_zeros: 2,
+///         },
+///         Chunk::Text(".png"),
+///     ],
+///     chunks
+/// );
+/// ```
+#[inline]
+pub fn chunks(s: &str) -> Chunks<'_> {
+    Chunks {
+        s,
+    }
+}
+
+/// An iterator over the chunks of a string, created by [`chunks`].
+#[derive(Debug, Clone)]
+pub struct Chunks<'a> {
+    s: &'a str,
+}
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = Chunk<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Chunk<'a>> {
+        let bytes = self.s.as_bytes();
+
+        let first = *bytes.first()?;
+
+        let chunk = if first.is_ascii_digit() {
+            let leading_zeros = bytes.iter().take_while(|&&b| b == b'0').count();
+            let len = leading_zeros
+                + bytes[leading_zeros..].iter().take_while(|b| b.is_ascii_digit()).count();
+
+            let digits = &self.s[..len];
+
+            self.s = &self.s[len..];
+
+            Chunk::Number {
+                digits,
+                value_digits: &digits[leading_zeros..],
+                leading_zeros,
+            }
+        } else {
+            // ASCII digits never appear inside a multi-byte UTF-8 sequence, so `len` is on a character boundary
+            let len = bytes.iter().position(|b| b.is_ascii_digit()).unwrap_or(bytes.len());
+
+            let text = &self.s[..len];
+
+            self.s = &self.s[len..];
+
+            Chunk::Text(text)
+        };
+
+        Some(chunk)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.s.is_empty() {
+            (0, Some(0))
+        } else {
+            (1, Some(self.s.len()))
+        }
+    }
+}
+
+impl FusedIterator for Chunks<'_> {}
@@ -0,0 +1,985 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::numerals::{
+    decimal_digit_zero, is_cjk_numeral, is_digit_separator, is_roman_numeral, parse_cjk_numerals,
+    parse_roman_numeral,
+};
+
+/// How a decimal point (`'.'`) between two decimal digit sequences is read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecimalMode {
+    /// The digit sequences are two integers, like the components of a version string, so `"v1.5"` is less than `"v1.10"`. This is the default.
+    Integer,
+    /// The digit sequences are the integer part and the fractional part of one decimal number, so `"scale_0.25"` is less than `"scale_0.3"`. Fractional parts are compared digit by digit, and decimal numbers having the same value are told apart by their trailing zeros if leading zeros are used as a tie-breaker, so `"0.3"` is less than `"0.30"`.
+    Fraction,
+}
+
+impl Default for DecimalMode {
+    #[inline]
+    fn default() -> Self {
+        DecimalMode::Integer
+    }
+}
+
+/// How the extension of a file name takes part in the comparison.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExtensionMode {
+    /// The whole string is compared at once, so the extension is just a part of it. This is the default.
+    Whole,
+    /// The file stems are compared first, and the extensions are compared only if the stems are equal, so `"a.txt"` is less than `"a-b.md"`.
+    StemFirst,
+    /// The extensions are compared first, and the file stems are compared only if the extensions are equal, so files are grouped by their extensions. A file name without an extension has the empty extension, which is the least.
+    ExtensionFirst,
+}
+
+impl Default for ExtensionMode {
+    #[inline]
+    fn default() -> Self {
+        ExtensionMode::Whole
+    }
+}
+
+/// Which part of a file name is its extension.
+///
+/// Only the part after the last `'/'` is treated as the file name, and a leading dot of the file name (e.g. `".bashrc"`) never starts an extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExtensionPolicy {
+    /// The extension is the part after the last dot, like `Path::extension`, so the extension of `"report.10.tar.gz"` is `"gz"`. This is the default.
+    Last,
+    /// The extension is the part after the first dot, so the extension of `"report.10.tar.gz"` is `"10.tar.gz"`.
+    All,
+    /// The extension is the longest listed multi-part extension (e.g. `"tar.gz"`) that the file name ends with, compared ASCII case-insensitively, or else the part after the last dot. [`COMPOUND_EXTENSIONS`] is a list of the common ones.
+    Known(&'static [&'static str]),
+}
+
+impl Default for ExtensionPolicy {
+    #[inline]
+    fn default() -> Self {
+        ExtensionPolicy::Last
+    }
+}
+
+/// Common multi-part extensions, for [`ExtensionPolicy::Known`].
+pub const COMPOUND_EXTENSIONS: &[&str] =
+    &["tar.gz", "tar.bz2", "tar.xz", "tar.zst", "tar.lz", "tar.lz4", "tar.lzma", "tar.Z", "tar.br"];
+
+impl ExtensionPolicy {
+    /// Split a file name into its stem and its extension, without the dot between them.
+    fn split<'a>(&self, s: &'a str) -> (&'a str, &'a str) {
+        let name_start = s.rfind('/').map_or(0, |i| i + 1);
+        let name = &s[name_start..];
+
+        let dot = match self {
+            ExtensionPolicy::Last => name.rfind('.'),
+            ExtensionPolicy::All => {
+                name.char_indices().skip(1).find(|&(_, c)| c == '.').map(|(i, _)| i)
+            },
+            ExtensionPolicy::Known(extensions) => extensions
+                .iter()
+                .filter_map(|e| {
+                    let dot = name.len().checked_sub(e.len() + 1)?;
+
+                    if name.as_bytes()[dot] == b'.' && name[dot + 1..].eq_ignore_ascii_case(e) {
+                        Some(dot)
+                    } else {
+                        None
+                    }
+                })
+                .min()
+                .or_else(|| name.rfind('.')),
+        };
+
+        match dot {
+            Some(dot) if dot > 0 => (&s[..name_start + dot], &s[name_start + dot + 1..]),
+            _ => (s, ""),
+        }
+    }
+}
+
+/// A configurable alphanumeric comparator.
+///
+/// `Collator::new()` (or `Collator::default()`) produces the same order as [`compare_str`](crate::compare_str). Each builder method switches one rule of that order on or off.
+///
+/// ```rust
+/// use core::cmp::Ordering;
+///
+/// use alphanumeric_sort::Collator;
+///
+/// let collator = Collator::new().leading_zeros(false);
+///
+/// assert_eq!(Ordering::Equal, collator.compare("shot-01", "shot-1"));
+/// assert_eq!(
+///     Ordering::Greater,
+///     alphanumeric_sort::compare_str("shot-01", "shot-1")
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Collator {
+    leading_zeros:     bool,
+    flip_after_number: bool,
+    case_insensitive:  bool,
+    unicode_digits:    bool,
+    cjk_numerals:      bool,
+    roman_numerals:    bool,
+    signed_numbers:    bool,
+    decimal_mode:      DecimalMode,
+    digit_grouping:    bool,
+    hexadecimal:       bool,
+    extension_mode:    ExtensionMode,
+    extension_policy:  ExtensionPolicy,
+}
+
+impl Default for Collator {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collator {
+    /// Create a collator whose order is the same as [`compare_str`](crate::compare_str).
+    #[inline]
+    pub const fn new() -> Self {
+        Collator {
+            leading_zeros:     true,
+            flip_after_number: true,
+            case_insensitive:  false,
+            unicode_digits:    false,
+            cjk_numerals:      false,
+            roman_numerals:    false,
+            signed_numbers:    false,
+            decimal_mode:      DecimalMode::Integer,
+            digit_grouping:    false,
+            hexadecimal:       false,
+            extension_mode:    ExtensionMode::Whole,
+            extension_policy:  ExtensionPolicy::Last,
+        }
+    }
+
+    /// Whether leading zeros are used as a tie-breaker between digit sequences having the same numeric value. The default value is `true`, so `"0001"` is greater than `"001"`.
+    ///
+    /// If it is disabled, `"0001"` and `"001"` are equal.
+    #[inline]
+    pub const fn leading_zeros(mut self, enable: bool) -> Self {
+        self.leading_zeros = enable;
+
+        self
+    }
+
+    /// Whether the ordering of two different characters is reversed when they follow equal digit sequences and are on different sides of U+00FF. The default value is `true`, so `"第1章"` is less than `"第1-2章"`.
+    ///
+    /// If it is disabled, the characters after digit sequences are compared by their Unicode scalar values like anywhere else.
+    #[inline]
+    pub const fn flip_after_number(mut self, enable: bool) -> Self {
+        self.flip_after_number = enable;
+
+        self
+    }
+
+    /// Whether non-numeric characters are compared case-insensitively. The default value is `false`.
+    ///
+    /// If it is enabled, characters are compared after being mapped to uppercase and then to lowercase, using only the mappings to single characters. This agrees with Unicode simple case folding for most characters, but is looser for a few, e.g. the dotless `'ı'` is considered equal to `'i'`. When two strings are equal in that way, the first case difference decides the order, so `"Shot-2"` is still not equal to `"shot-2"`.
+    #[inline]
+    pub const fn case_insensitive(mut self, enable: bool) -> Self {
+        self.case_insensitive = enable;
+
+        self
+    }
+
+    /// Whether sequences of any Unicode decimal digits (`Nd`), such as `"１０"`, `"١٠"` and `"१०"`, are compared by their numeric values. The default value is `false`, so only ASCII digit sequences are.
+    ///
+    /// A digit sequence never mixes scripts. It ends where the next digit belongs to another script, and that digit starts a new sequence, so `"1١"` is read as the number 1 followed by another number 1. Sequences having the same numeric value are told apart by their leading zeros, and then by their scripts in the order of Unicode code points, if leading zeros are used as a tie-breaker.
+    #[inline]
+    pub const fn unicode_digits(mut self, enable: bool) -> Self {
+        self.unicode_digits = enable;
+
+        self
+    }
+
+    /// Whether sequences of Chinese and Japanese numerals, such as `"十二"`, `"一百零三"` and `"二〇二三"`, are compared by their numeric values. The default value is `false`.
+    ///
+    /// The digits `〇`, `零`, `一` to `九` (and `两`, `兩`) and the multipliers `十`, `百`, `千`, `万` (`萬`) and `亿` (`億`) are recognized. A sequence with multipliers is read positionally, where `零` marks a skipped position, and a sequence without multipliers is read digit by digit. These numbers are compared with other numbers, e.g. ASCII digit sequences, by value, so `"第十二章"` is greater than `"第3章"`. Numbers having the same numeric value are told apart by their leading zeros, and then by their spellings, if leading zeros are used as a tie-breaker.
+    ///
+    /// Note that every such character is read as a number once it is enabled, even inside words like `"统一"`.
+    #[inline]
+    pub const fn cjk_numerals(mut self, enable: bool) -> Self {
+        self.cjk_numerals = enable;
+
+        self
+    }
+
+    /// Whether Roman numerals, such as `"II"`, `"IX"` and `"iv"`, are compared by their numeric values. The default value is `false`.
+    ///
+    /// To avoid false positives, only a whole word, which is not preceded or followed by a letter, can be a Roman numeral, and it has to be in the canonical form from `I` to `MMMCMXCIX`, written entirely in uppercase or entirely in lowercase. Hence `"Part IX"` is greater than `"Part V"`, but `"MIX"` in `"REMIX"` and `"DC"` in `"ADC"` are just letters. Roman numerals are compared with other numbers by value, and equal ones are told apart by their spellings if leading zeros are used as a tie-breaker.
+    #[inline]
+    pub const fn roman_numerals(mut self, enable: bool) -> Self {
+        self.roman_numerals = enable;
+
+        self
+    }
+
+    /// Whether a minus sign (`'-'`) directly before a decimal digit sequence makes the number negative, if the minus sign is not preceded by a letter or a digit. The default value is `false`.
+    ///
+    /// Negative numbers are less than zero, so `"offset_-3.log"` is less than `"offset_0.log"` and `"temp--5"` is less than `"temp-10"`. A minus sign after an alphanumeric character, like the one in `"temp-10"` or `"5-3"`, is just a hyphen.
+    #[inline]
+    pub const fn signed_numbers(mut self, enable: bool) -> Self {
+        self.signed_numbers = enable;
+
+        self
+    }
+
+    /// How a decimal point (`'.'`) between two decimal digit sequences is read. The default value is `DecimalMode::Integer`.
+    ///
+    /// ```rust
+    /// use core::cmp::Ordering;
+    ///
+    /// use alphanumeric_sort::{Collator, DecimalMode};
+    ///
+    /// let collator = Collator::new().decimal_mode(DecimalMode::Fraction);
+    ///
+    /// assert_eq!(Ordering::Less, collator.compare("scale_0.25", "scale_0.3"));
+    /// assert_eq!(
+    ///     Ordering::Greater,
+    ///     alphanumeric_sort::compare_str("scale_0.25", "scale_0.3")
+    /// );
+    /// ```
+    #[inline]
+    pub const fn decimal_mode(mut self, mode: DecimalMode) -> Self {
+        self.decimal_mode = mode;
+
+        self
+    }
+
+    /// Whether digit grouping separators (`','`, `'_'`, `'\''` and U+2009 THIN SPACE) inside decimal digit sequences are accepted, so that a grouped number is compared as a single value. The default value is `false`.
+    ///
+    /// The grouping has to be well formed. The first group has one to three digits, and each following group has exactly three digits after the same separator. A separator which does not start such a group ends the number, so `"revenue_1,200.csv"` is greater than `"revenue_980.csv"`, while `"1,2345"` is read as the number 1 followed by `","` and the number 2345. Numbers having the same value are told apart by their spellings if leading zeros are used as a tie-breaker.
+    #[inline]
+    pub const fn digit_grouping(mut self, enable: bool) -> Self {
+        self.digit_grouping = enable;
+
+        self
+    }
+
+    /// Whether a `"0x"` or `"0X"` prefix starts a hexadecimal number, such as `"0x1f"`, which is compared by its value. The default value is `false`.
+    ///
+    /// Hexadecimal numbers are compared with other numbers by value, so `"dump_0x1f.bin"` is greater than `"dump_0xa.bin"` and `"dump_30.bin"`. Numbers having the same value are told apart by their leading zeros, and then by their spellings (e.g. letter cases), if leading zeros are used as a tie-breaker. Numbers of any length are compared by value, including hexadecimal numbers beyond the range of `u128`.
+    #[inline]
+    pub const fn hexadecimal(mut self, enable: bool) -> Self {
+        self.hexadecimal = enable;
+
+        self
+    }
+
+    /// How the extension of a file name takes part in the comparison. The default value is `ExtensionMode::Whole`.
+    ///
+    /// ```rust
+    /// use core::cmp::Ordering;
+    ///
+    /// use alphanumeric_sort::{Collator, ExtensionMode};
+    ///
+    /// let collator = Collator::new().extension_mode(ExtensionMode::StemFirst);
+    ///
+    /// assert_eq!(Ordering::Less, collator.compare("a.txt", "a-b.md"));
+    /// assert_eq!(
+    ///     Ordering::Greater,
+    ///     alphanumeric_sort::compare_str("a.txt", "a-b.md")
+    /// );
+    /// ```
+    #[inline]
+    pub const fn extension_mode(mut self, mode: ExtensionMode) -> Self {
+        self.extension_mode = mode;
+
+        self
+    }
+
+    /// Which part of a file name is its extension, for the extension modes other than `ExtensionMode::Whole`. The default value is `ExtensionPolicy::Last`.
+    ///
+    /// ```rust
+    /// use core::cmp::Ordering;
+    ///
+    /// use alphanumeric_sort::{
+    ///     Collator, ExtensionMode, ExtensionPolicy, COMPOUND_EXTENSIONS,
+    /// };
+    ///
+    /// let collator = Collator::new()
+    ///     .extension_mode(ExtensionMode::ExtensionFirst)
+    ///     .extension_policy(ExtensionPolicy::Known(COMPOUND_EXTENSIONS));
+    ///
+    /// assert_eq!(
+    ///     Ordering::Greater,
+    ///     collator.compare("report.10.tar.gz", "report.9.gz")
+    /// );
+    /// ```
+    #[inline]
+    pub const fn extension_policy(mut self, policy: ExtensionPolicy) -> Self {
+        self.extension_policy = policy;
+
+        self
+    }
+
+    /// Compare two strings.
+    pub fn compare<A: AsRef<str>, B: AsRef<str>>(&self, a: A, b: B) -> Ordering {
+        // the default preset is `compare_str` itself, so its rules have only one implementation
+        if *self == Collator::new() {
+            return crate::compare_str(a, b);
+        }
+
+        let a = a.as_ref();
+        let b = b.as_ref();
+
+        let ordering = match self.extension_mode {
+            ExtensionMode::Whole => Ordering::Equal,
+            ExtensionMode::StemFirst => {
+                let (sa, ea) = self.extension_policy.split(a);
+                let (sb, eb) = self.extension_policy.split(b);
+
+                self.compare_str(sa, sb).then_with(|| self.compare_str(ea, eb))
+            },
+            ExtensionMode::ExtensionFirst => {
+                let (sa, ea) = self.extension_policy.split(a);
+                let (sb, eb) = self.extension_policy.split(b);
+
+                self.compare_str(ea, eb).then_with(|| self.compare_str(sa, sb))
+            },
+        };
+
+        // the whole strings are still compared to tell apart something like "a" and "a."
+        ordering.then_with(|| self.compare_str(a, b))
+    }
+
+    fn compare_str(&self, a: &str, b: &str) -> Ordering {
+        let mut ta = Tokens::new(a, self);
+        let mut tb = Tokens::new(b, self);
+
+        // this flag is to handle something like "1點" < "1-1點"
+        let mut last_is_number = false;
+
+        // this is to handle something like "1a" > "01", and case differences
+        let mut pre_answer = Ordering::Equal;
+
+        loop {
+            let (xa, xb) = match (ta.next(), tb.next()) {
+                (Some(xa), Some(xb)) => (xa, xb),
+                (Some(_), None) => return Ordering::Greater,
+                (None, Some(_)) => return Ordering::Less,
+                (None, None) => return pre_answer,
+            };
+
+            if let (Token::Number(na), Token::Number(nb)) = (&xa, &xb) {
+                match na.cmp_value(nb) {
+                    Ordering::Equal => {
+                        last_is_number = true;
+
+                        if self.leading_zeros && pre_answer == Ordering::Equal {
+                            pre_answer = na.cmp_spelling(nb);
+                        }
+                    },
+                    ordering => return ordering,
+                }
+
+                continue;
+            }
+
+            let ca = self.primary_char(&xa);
+            let cb = self.primary_char(&xb);
+
+            let ordering = ca.cmp(&cb);
+
+            if ordering == Ordering::Equal {
+                last_is_number = false;
+
+                if pre_answer == Ordering::Equal {
+                    pre_answer = xa.raw_char().cmp(&xb.raw_char());
+                }
+            } else if self.flip_after_number
+                && last_is_number
+                && (ca > (255 as char)) ^ (cb > (255 as char))
+            {
+                return ordering.reverse();
+            } else {
+                return ordering;
+            }
+        }
+    }
+
+    /// The character used for the primary comparison with a non-numeric character.
+    #[inline]
+    fn primary_char(&self, token: &Token) -> char {
+        match token {
+            Token::Char(c) => {
+                if self.case_insensitive {
+                    fold_case(*c)
+                } else {
+                    *c
+                }
+            },
+            // every number is compared with characters as a whole, like a digit
+            Token::Number(_) => '0',
+        }
+    }
+}
+
+enum Token<'a> {
+    Char(char),
+    Number(Number<'a>),
+}
+
+impl<'a> Token<'a> {
+    #[inline]
+    fn raw_char(&self) -> char {
+        match self {
+            Token::Char(c) => *c,
+            Token::Number(_) => '0',
+        }
+    }
+}
+
+struct Number<'a> {
+    /// Whether the number has a minus sign.
+    negative:  bool,
+    /// The count of the leading zeros.
+    zeros:     usize,
+    /// The numeric value without the leading zeros.
+    magnitude: Magnitude<'a>,
+    /// The whole number, including the leading zeros.
+    text:      &'a str,
+}
+
+impl<'a> Number<'a> {
+    #[inline]
+    fn is_negative(&self) -> bool {
+        // -0 is zero
+        self.negative && !self.magnitude.is_zero()
+    }
+
+    #[inline]
+    fn cmp_value(&self, other: &Number) -> Ordering {
+        match (self.is_negative(), other.is_negative()) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+        }
+    }
+
+    #[inline]
+    fn cmp_spelling(&self, other: &Number) -> Ordering {
+        self.zeros.cmp(&other.zeros).then_with(|| self.text.cmp(other.text))
+    }
+}
+
+enum Magnitude<'a> {
+    /// Decimal digits.
+    Digits {
+        /// The digits of the integer part.
+        digits:   &'a str,
+        /// The count of the digits of the integer part.
+        len:      usize,
+        /// The digits of the fractional part, which is empty if there is no fractional part.
+        fraction: &'a str,
+        /// The digit zero of the script which the digits belong to.
+        zero:     u32,
+        /// Whether the digits of the integer part are grouped by separators.
+        grouped:  bool,
+    },
+    /// A value parsed from numerals which are not decimal digits.
+    Value(u128),
+    /// Hexadecimal digits beyond the range of `u128`.
+    Hex {
+        /// The digits.
+        digits: &'a str,
+        /// The count of the digits.
+        len:    usize,
+    },
+}
+
+impl<'a> Magnitude<'a> {
+    #[inline]
+    fn is_zero(&self) -> bool {
+        match self {
+            Magnitude::Digits {
+                len,
+                fraction,
+                zero,
+                ..
+            } => *len == 0 && fraction.chars().all(|c| c as u32 == *zero),
+            Magnitude::Value(value) => *value == 0,
+            Magnitude::Hex {
+                ..
+            } => false,
+        }
+    }
+
+    fn cmp(&self, other: &Magnitude) -> Ordering {
+        match (self, other) {
+            (
+                Magnitude::Digits {
+                    digits: da,
+                    len: la,
+                    fraction: fa,
+                    zero: za,
+                    grouped: ga,
+                },
+                Magnitude::Digits {
+                    digits: db,
+                    len: lb,
+                    fraction: fb,
+                    zero: zb,
+                    grouped: gb,
+                },
+            ) => la
+                .cmp(lb)
+                .then_with(|| {
+                    if za == zb && !ga && !gb {
+                        // digits in the same script are encoded in the same length
+                        da.cmp(db)
+                    } else {
+                        digit_values(da, *za).cmp(digit_values(db, *zb))
+                    }
+                })
+                .then_with(|| cmp_fractions(digit_values(fa, *za), digit_values(fb, *zb))),
+            (Magnitude::Value(va), Magnitude::Value(vb)) => va.cmp(vb),
+            (
+                Magnitude::Digits {
+                    digits,
+                    len,
+                    fraction,
+                    zero,
+                    ..
+                },
+                Magnitude::Value(value),
+            ) => cmp_digits_with_value(digits, *len, *zero, *value)
+                .then_with(|| cmp_fractions(digit_values(fraction, *zero), core::iter::empty())),
+            (
+                Magnitude::Value(_),
+                Magnitude::Digits {
+                    ..
+                },
+            ) => other.cmp(self).reverse(),
+            (
+                Magnitude::Hex {
+                    digits: da,
+                    len: la,
+                },
+                Magnitude::Hex {
+                    digits: db,
+                    len: lb,
+                },
+            ) => la.cmp(lb).then_with(|| hex_digit_values(da).cmp(hex_digit_values(db))),
+            (
+                Magnitude::Digits {
+                    digits,
+                    len,
+                    fraction,
+                    zero,
+                    ..
+                },
+                Magnitude::Hex {
+                    digits: hex, ..
+                },
+            ) => cmp_digits_with_hex(digits, *len, *zero, hex)
+                .then_with(|| cmp_fractions(digit_values(fraction, *zero), core::iter::empty())),
+            (
+                Magnitude::Hex {
+                    ..
+                },
+                Magnitude::Digits {
+                    ..
+                },
+            ) => other.cmp(self).reverse(),
+            // every value is in the range of `u128`
+            (
+                Magnitude::Hex {
+                    ..
+                },
+                _,
+            ) => Ordering::Greater,
+            (
+                _,
+                Magnitude::Hex {
+                    ..
+                },
+            ) => Ordering::Less,
+        }
+    }
+}
+
+/// Get the values of the digits in the script of `zero`, skipping separators.
+#[inline]
+fn digit_values(digits: &str, zero: u32) -> impl Iterator<Item = u32> + '_ {
+    digits.chars().map(move |c| (c as u32).wrapping_sub(zero)).filter(|&v| v < 10)
+}
+
+#[inline]
+fn hex_digit_values(digits: &str) -> impl Iterator<Item = u32> + '_ {
+    digits.chars().filter_map(|c| c.to_digit(16))
+}
+
+/// Compare two fractional parts digit by digit. The missing digits are zeros.
+fn cmp_fractions(mut fa: impl Iterator<Item = u32>, mut fb: impl Iterator<Item = u32>) -> Ordering {
+    loop {
+        match (fa.next(), fb.next()) {
+            (None, None) => return Ordering::Equal,
+            (da, db) => match da.unwrap_or(0).cmp(&db.unwrap_or(0)) {
+                Ordering::Equal => (),
+                ordering => return ordering,
+            },
+        }
+    }
+}
+
+fn cmp_digits_with_value(digits: &str, len: usize, zero: u32, mut value: u128) -> Ordering {
+    // u128::MAX has 39 decimal digits
+    let mut buffer = [0u32; 39];
+    let mut n = 0;
+
+    while value > 0 {
+        buffer[n] = (value % 10) as u32;
+        value /= 10;
+        n += 1;
+    }
+
+    len.cmp(&n).then_with(|| {
+        let da = digit_values(digits, zero);
+        let db = buffer[..n].iter().rev().copied();
+
+        da.cmp(db)
+    })
+}
+
+/// Compare decimal digits with hexadecimal digits beyond the range of `u128`, as numbers of any size.
+fn cmp_digits_with_hex(digits: &str, len: usize, zero: u32, hex: &str) -> Ordering {
+    // the hexadecimal number is at least 2^128, which has 39 decimal digits
+    if len < 39 {
+        return Ordering::Less;
+    }
+
+    // the decimal number in base 2^32, from the least significant limb
+    let mut limbs: Vec<u32> = Vec::with_capacity(len / 9 + 1);
+
+    for d in digit_values(digits, zero) {
+        let mut carry = d as u64;
+
+        for limb in limbs.iter_mut() {
+            let v = *limb as u64 * 10 + carry;
+
+            *limb = v as u32;
+            carry = v >> 32;
+        }
+
+        if carry > 0 {
+            limbs.push(carry as u32);
+        }
+    }
+
+    // eight hexadecimal digits make a limb
+    let hex_limbs = hex
+        .as_bytes()
+        .rchunks(8)
+        .map(|chunk| chunk.iter().fold(0, |v, &c| v << 4 | (c as char).to_digit(16).unwrap_or(0)));
+
+    // neither number has leading zeros, so the one with more limbs is greater
+    limbs.len().cmp(&hex_limbs.len()).then_with(|| limbs.iter().rev().copied().cmp(hex_limbs.rev()))
+}
+
+struct Tokens<'a> {
+    s:        &'a str,
+    /// The last character which has been read.
+    prev:     Option<char>,
+    collator: Collator,
+}
+
+impl<'a> Tokens<'a> {
+    #[inline]
+    fn new(s: &'a str, collator: &Collator) -> Self {
+        Tokens {
+            s,
+            prev: None,
+            collator: *collator,
+        }
+    }
+
+    /// Take the first `end` bytes as a number, which starts with a minus sign if `negative` is `true`.
+    #[inline]
+    fn take_number(
+        &mut self,
+        negative: bool,
+        zeros: usize,
+        magnitude: Magnitude<'a>,
+        end: usize,
+    ) -> Token<'a> {
+        let text = &self.s[..end];
+
+        self.s = &self.s[end..];
+        self.prev = text.chars().next_back();
+
+        Token::Number(Number {
+            negative,
+            zeros,
+            magnitude,
+            text,
+        })
+    }
+
+    /// Read a number of decimal digits in the script of `zero`, after a sign which is `sign` bytes long.
+    fn next_digits_number(&mut self, sign: usize, zero: u32) -> Token<'a> {
+        let s = &self.s[sign..];
+
+        let end = s
+            .char_indices()
+            .find(|(_, c)| decimal_digit_zero(*c) != Some(zero))
+            .map(|(i, _)| i)
+            .unwrap_or(s.len());
+
+        let (end, grouped) = self.scan_groups(s, end, zero);
+
+        let mut zeros = 0;
+        let mut start = end;
+        let mut len = 0;
+
+        for (i, c) in s[..end].char_indices() {
+            if decimal_digit_zero(c) != Some(zero) {
+                // a separator
+                continue;
+            }
+
+            if len == 0 && c as u32 == zero {
+                zeros += 1;
+            } else {
+                if len == 0 {
+                    start = i;
+                }
+
+                len += 1;
+            }
+        }
+
+        let digits = &s[start..end];
+
+        let (fraction, end) = self.scan_fraction(s, end, zero);
+
+        let magnitude = Magnitude::Digits {
+            digits,
+            len,
+            fraction,
+            zero,
+            grouped,
+        };
+
+        self.take_number(sign > 0, zeros, magnitude, sign + end)
+    }
+
+    /// Read the groups of three digits after the first group `s[..end]`, if digit grouping is enabled and the grouping is well formed. Return the end of the last group and whether there is any group.
+    fn scan_groups(&self, s: &str, mut end: usize, zero: u32) -> (usize, bool) {
+        if !self.collator.digit_grouping || s[..end].chars().count() > 3 {
+            return (end, false);
+        }
+
+        let separator = match s[end..].chars().next() {
+            Some(c) if is_digit_separator(c) => c,
+            _ => return (end, false),
+        };
+
+        let mut grouped = false;
+
+        while s[end..].starts_with(separator) {
+            let group = &s[end + separator.len_utf8()..];
+
+            let mut count = 0;
+            let mut group_end = group.len();
+
+            for (i, c) in group.char_indices() {
+                if decimal_digit_zero(c) != Some(zero) {
+                    group_end = i;
+                    break;
+                }
+
+                count += 1;
+
+                if count > 3 {
+                    break;
+                }
+            }
+
+            // every group after the first one has exactly three digits
+            if count != 3 {
+                break;
+            }
+
+            end += separator.len_utf8() + group_end;
+            grouped = true;
+        }
+
+        (end, grouped)
+    }
+
+    /// Read the fractional part of a decimal number, if `s[end..]` starts with a decimal point followed by digits of the same script. Return the digits of the fractional part and the end of the whole number.
+    #[inline]
+    fn scan_fraction(&self, s: &'a str, end: usize, zero: u32) -> (&'a str, usize) {
+        if self.collator.decimal_mode != DecimalMode::Fraction || !s[end..].starts_with('.') {
+            return ("", end);
+        }
+
+        let start = end + 1;
+
+        let fraction_end = s[start..]
+            .char_indices()
+            .find(|(_, c)| decimal_digit_zero(*c) != Some(zero))
+            .map(|(i, _)| start + i)
+            .unwrap_or(s.len());
+
+        if fraction_end == start {
+            ("", end)
+        } else {
+            (&s[start..fraction_end], fraction_end)
+        }
+    }
+
+    /// Read a number of decimal digits after a sign which is `sign` bytes long, if there is one.
+    #[inline]
+    fn next_decimal_number(&mut self, sign: usize) -> Option<Token<'a>> {
+        let c = self.s[sign..].chars().next()?;
+
+        let zero = if c.is_ascii_digit() {
+            '0' as u32
+        } else if self.collator.unicode_digits {
+            decimal_digit_zero(c)?
+        } else {
+            return None;
+        };
+
+        Some(self.next_digits_number(sign, zero))
+    }
+
+    fn next_hex_number(&mut self) -> Option<Token<'a>> {
+        let bytes = self.s.as_bytes();
+
+        if bytes.len() < 3
+            || bytes[0] != b'0'
+            || (bytes[1] != b'x' && bytes[1] != b'X')
+            || !bytes[2].is_ascii_hexdigit()
+        {
+            return None;
+        }
+
+        let end = bytes[2..]
+            .iter()
+            .position(|b| !b.is_ascii_hexdigit())
+            .map(|i| 2 + i)
+            .unwrap_or(bytes.len());
+        let zeros = bytes[2..end].iter().take_while(|&&b| b == b'0').count();
+
+        let digits = &self.s[2 + zeros..end];
+        let len = digits.len();
+
+        let magnitude = if len <= 32 {
+            Magnitude::Value(hex_digit_values(digits).fold(0, |v, d| v << 4 | d as u128))
+        } else {
+            Magnitude::Hex {
+                digits,
+                len,
+            }
+        };
+
+        Some(self.take_number(false, zeros, magnitude, end))
+    }
+
+    fn next_cjk_number(&mut self) -> Token<'a> {
+        let (value, zeros, end) = parse_cjk_numerals(self.s);
+
+        self.take_number(false, zeros, Magnitude::Value(value), end)
+    }
+
+    fn next_roman_number(&mut self) -> Option<Token<'a>> {
+        // a Roman numeral has to be a whole word
+        if self.prev.is_some_and(char::is_alphabetic) {
+            return None;
+        }
+
+        let end = self
+            .s
+            .char_indices()
+            .find(|(_, c)| !c.is_alphabetic())
+            .map(|(i, _)| i)
+            .unwrap_or(self.s.len());
+
+        let value = parse_roman_numeral(&self.s[..end])?;
+
+        Some(self.take_number(false, 0, Magnitude::Value(value), end))
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let c = self.s.chars().next()?;
+
+        if self.collator.hexadecimal && c == '0' {
+            if let Some(token) = self.next_hex_number() {
+                return Some(token);
+            }
+        }
+
+        if let Some(token) = self.next_decimal_number(0) {
+            return Some(token);
+        }
+
+        if self.collator.signed_numbers && c == '-' && !self.prev.is_some_and(char::is_alphanumeric)
+        {
+            if let Some(token) = self.next_decimal_number(1) {
+                return Some(token);
+            }
+        }
+
+        if self.collator.cjk_numerals && is_cjk_numeral(c) {
+            return Some(self.next_cjk_number());
+        }
+
+        if self.collator.roman_numerals && is_roman_numeral(c) {
+            if let Some(token) = self.next_roman_number() {
+                return Some(token);
+            }
+        }
+
+        self.s = &self.s[c.len_utf8()..];
+        self.prev = Some(c);
+
+        Some(Token::Char(c))
+    }
+}
+
+/// Map a character for case-insensitive comparison, by mapping it to uppercase and then to lowercase.
+///
+/// Mappings which expand to more than one character are not used. This is close to Unicode simple case folding, but it also maps a few characters such as `'ı'` (U+0131) to ASCII letters, which simple case folding does not.
+fn fold_case(c: char) -> char {
+    if c.is_ascii() {
+        return c.to_ascii_lowercase();
+    }
+
+    let mut upper = c.to_uppercase();
+
+    let base = match (upper.next(), upper.next()) {
+        (Some(u), None) => u,
+        _ => c,
+    };
+
+    let mut lower = base.to_lowercase();
+
+    match (lower.next(), lower.next()) {
+        (Some(l), None) => l,
+        _ => {
+            if base == c {
+                c
+            } else {
+                let mut lower = c.to_lowercase();
+
+                match (lower.next(), lower.next()) {
+                    (Some(l), None) => l,
+                    _ => c,
+                }
+            }
+        },
+    }
+}
@@ -41,7 +41,41 @@ assert_eq!([Path::new("shot-1"), Path::new("shot-2"), Path::new("shot-11")], pat
 
 ASCII digit sequences are compared by their numeric values instead of their lexicographical order. When two digit sequences have the same numeric value, leading zeros are used as a tie-breaker, so `"0001"` is greater than `"001"`.
 
-This crate is not a locale-aware collation library. Non-numeric characters are compared by their Unicode scalar values, except after equal digit sequences: if the next different characters are on different sides of U+00FF, their ordering is reversed. This keeps cases like `"第1章"` less than `"第1-2章"`, while `"1"` is still less than `"中"`.
+This crate is not a locale-aware collation library. Non-numeric characters are compared by their Unicode scalar values, except after equal digit sequences: if the next different characters are on different sides of U+00FF, their ordering is reversed. This keeps cases like `"第1章"` less than `"第1-2章"`, while `"1"` is still less than `"中"`. Byte strings and UTF-16 strings can be compared with `compare_bytes` and `compare_utf16`, and `OsStr`, `CStr` and `Path` values which are not valid UTF-8 are compared by their bytes in the same way, where a byte which is not a part of a character is greater than any character.
+
+Paths are compared as whole strings, so a separator is compared like any other character. `compare_path_components` and `sort_path_slice_by_components` compare paths component by component instead, which keeps the contents of a directory grouped together. `sort_path_slice_dirs_first` and `sort_path_slice_files_first` put directories before or after files, and sort each group alphanumerically.
+
+## Custom Rules
+
+The rules above can be switched on or off individually with a `Collator`. `Collator::new()` compares strings in the same way as `compare_str`. Extra rules which are disabled by default can be enabled with it as well, so that Unicode decimal digits (e.g. `"１０"`), Chinese and Japanese numerals (e.g. `"十二"`), Roman numerals (e.g. `"IX"`), negative numbers (e.g. `"-3"`), decimal fractions (e.g. `"0.25"`), grouped digits (e.g. `"1,200"`) and hexadecimal numbers (e.g. `"0x1f"`) are compared by their values. File names can also be compared by their stems first or by their extensions first, with a policy for multi-part extensions like `.tar.gz`.
+
+```rust
+use alphanumeric_sort::Collator;
+
+let collator = Collator::new().case_insensitive(true).leading_zeros(false);
+
+let mut names = ["Shot-2", "shot-1", "shot-011"];
+
+names.sort_by(|a, b| collator.compare(a, b));
+
+assert_eq!(["shot-1", "Shot-2", "shot-011"], names);
+```
+
+The commonly used case-insensitive rule also has its own functions, such as `compare_str_case_insensitive`, `sort_str_slice_case_insensitive` and `sort_path_slice_case_insensitive`.
+
+## Version Strings
+
+`compare_version` follows the [Semantic Versioning 2.0.0](https://semver.org/spec/v2.0.0.html) precedence rules, so a pre-release comes before its release and build metadata is ignored. Anything after the version, like a file extension, is compared with the alphanumeric algorithm.
+
+Package versions can be compared like `dpkg` and `rpm` do with `compare_debian_version` and `compare_rpm_version`, which handle epochs (e.g. `"2:1.0"`), tilde pre-releases (e.g. `"1.0~rc1"`) and revisions (e.g. `"1.0-3"`). Like RPM, `compare_rpm_version` ignores the releases unless both versions have one, so it is not a total order for a mix of versions with and without releases, and must not be used to sort such a mix.
+
+```rust
+let mut names = ["app-1.0.0.tar.gz", "app-1.0.0-rc.2.tar.gz", "app-1.0.0-rc.10.tar.gz", "app-0.9.1.tar.gz"];
+
+alphanumeric_sort::sort_version_slice(&mut names);
+
+assert_eq!(["app-0.9.1.tar.gz", "app-1.0.0-rc.2.tar.gz", "app-1.0.0-rc.10.tar.gz", "app-1.0.0.tar.gz"], names);
+```
 
 ## About the `compare_*` Functions and the `sort_*` Functions
 
@@ -61,6 +95,36 @@ assert_eq!([Path::new("shot-1"), Path::new("shot-2"), Path::new("shot-11")], pat
 
 But it is not recommended because the `compare_*` functions try to convert data (e.g `Path`, `CStr`) to `&str` every time in its execution and thus they are slower than the `sort_*` functions when sorting a slice.
 
+## Ordered Wrappers
+
+`AlphanumericStr`, `AlphanumericString` and `Alphanumeric<T>` wrap strings so that their `Ord` is `compare_str`, which makes them usable as keys of `BTreeMap` and `BTreeSet`, in `BinaryHeap` and with `binary_search`. Their `Eq` and `Hash` agree with the ordering, since `compare_str` only considers identical strings equal. `AlphanumericPath` and `AlphanumericPathBuf` do the same for paths with `compare_path`.
+
+## Binary Sort Keys
+
+`sort_key` (or `encode_into` with a reused buffer) creates a byte string from a string, such that comparing two keys bytewise gives the same result as `compare_str`. The keys can be stored in a database (e.g. a `BLOB` column or the keys of RocksDB) whose byte ordering is then the alphanumeric order. `sort_str_slice_cached` sorts a large `str` slice by the keys, which scans every string only once.
+
+## Chunks
+
+`chunks` splits a string into `Chunk::Text` and `Chunk::Number` parts in the same way as `compare_str` does, which is useful for highlighting or editing the numbers in names.
+
+## Partial Sorting
+
+To get only a few elements out of a large slice, `partial_sort_str_slice` sorts just the `k` least elements, `top_k_by_str_key` picks the `k` greatest elements in descending order, and `select_nth_str` finds the element at a sorted position, without sorting the whole slice. The `Path` and `OsStr` equivalents are `*_path*` and `*_os_str*`.
+
+## Binary Searching
+
+To keep a sorted slice sorted, `binary_search_str`, `binary_search_by_str_key`, `partition_point_str` and `insert_sorted_str` use the same comparator as the `sort_*` functions. The `Path` equivalents are `*_path*`, which handle paths that are not valid UTF-8 in the same way as `sort_path_slice`.
+
+## Parallel Sorting
+
+Enable the `rayon` feature to get the `par_*` functions, such as `par_sort_str_slice`, `par_sort_path_slice`, `par_sort_slice_by_str_key` and `par_sort_slice_by_path_key`, which sort large slices in parallel with [rayon](https://crates.io/crates/rayon). The `OsStr` and `CStr` equivalents are `*_os_str*` and `*_c_str*`.
+
+```toml
+[dependencies.alphanumeric-sort]
+version = "*"
+features = ["rayon"]
+```
+
 ## Version `1.3` to `1.4`
 
 No breaking change in API is made, though the order has some changes.
@@ -78,6 +142,10 @@ version = "*"
 default-features = false
 ```
 
+## Rust Version
+
+This crate requires Rust 1.74 or newer, to get the bytes of an `OsStr` on every platform with `OsStr::as_encoded_bytes`. Versions up to 1.5.8 only need Rust 1.56. The `rayon` feature is not covered by this minimum, since it needs the Rust version required by rayon, which is 1.80 for its current releases.
+
 ## Benchmark
 
 ```bash
@@ -90,27 +158,57 @@ cargo bench
 
 extern crate alloc; // used for sorting
 
+mod alphanumeric;
+#[cfg(feature = "std")]
+mod alphanumeric_path;
+mod bytes;
+mod chunks;
+mod collator;
+mod numerals;
+#[cfg(feature = "rayon")]
+mod par_functions;
+mod sort_key;
 #[cfg(feature = "std")]
 mod std_functions;
+mod utf16;
+mod version;
 
-use core::{cmp::Ordering, str::Chars};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
 
+pub use alphanumeric::*;
+#[cfg(feature = "std")]
+pub use alphanumeric_path::*;
+pub use bytes::*;
+pub use chunks::*;
+pub use collator::*;
+#[cfg(feature = "rayon")]
+pub use par_functions::*;
+pub use sort_key::*;
 #[cfg(feature = "std")]
 pub use std_functions::*;
+pub use utf16::*;
+pub use version::*;
 
 /// Compare two strings.
+#[inline]
 pub fn compare_str<A: AsRef<str>, B: AsRef<str>>(a: A, b: B) -> Ordering {
-    let mut c1 = a.as_ref().chars();
-    let mut c2 = b.as_ref().chars();
+    compare_units(a.as_ref().chars().map(u32::from), b.as_ref().chars().map(u32::from))
+}
 
+/// Compare two sequences of units in the same way as `compare_str`. A unit is a Unicode scalar value, or a value which does not collide with any of them for something which is not a Unicode scalar value.
+pub(crate) fn compare_units<I1: Iterator<Item = u32>, I2: Iterator<Item = u32>>(
+    mut c1: I1,
+    mut c2: I2,
+) -> Ordering {
     // this flag is to handle something like "1點" < "1-1點"
     let mut last_is_number = false;
 
     // this flag is to handle something like "1a" > "01"
     let mut pre_answer = Ordering::Equal;
 
-    let mut v1: Option<char> = None;
-    let mut v2: Option<char> = None;
+    let mut v1: Option<u32> = None;
+    let mut v2: Option<u32> = None;
 
     loop {
         let mut ca = {
@@ -141,7 +239,7 @@ pub fn compare_str<A: AsRef<str>, B: AsRef<str>>(a: A, b: B) -> Ordering {
             }
         };
 
-        if ca.is_ascii_digit() && cb.is_ascii_digit() {
+        if is_ascii_digit(ca) && is_ascii_digit(cb) {
             // count the digit length, but ignore the leading zeros and the following same part (prefix)
             let mut la = 1usize;
             let mut lb = 1usize;
@@ -150,10 +248,10 @@ pub fn compare_str<A: AsRef<str>, B: AsRef<str>>(a: A, b: B) -> Ordering {
             let mut lc = 0isize;
 
             // find the first non-zero digit in c1
-            while ca == '0' {
+            while ca == ZERO {
                 lc += 1;
                 if let Some(c) = c1.next() {
-                    if c.is_ascii_digit() {
+                    if is_ascii_digit(c) {
                         ca = c;
                     } else {
                         v1 = Some(c);
@@ -167,10 +265,10 @@ pub fn compare_str<A: AsRef<str>, B: AsRef<str>>(a: A, b: B) -> Ordering {
             }
 
             // find the first non-zero digit in c2
-            while cb == '0' {
+            while cb == ZERO {
                 lc -= 1;
                 if let Some(c) = c2.next() {
-                    if c.is_ascii_digit() {
+                    if is_ascii_digit(c) {
                         cb = c;
                     } else {
                         v2 = Some(c);
@@ -183,22 +281,6 @@ pub fn compare_str<A: AsRef<str>, B: AsRef<str>>(a: A, b: B) -> Ordering {
                 }
             }
 
-            // consume the remaining ascii digit
-            let consume_ascii_digit = |chars: &mut Chars, store: &mut Option<char>| {
-                let mut counter = 0;
-
-                for c in chars.by_ref() {
-                    if c.is_ascii_digit() {
-                        counter += 1;
-                    } else {
-                        *store = Some(c);
-                        break;
-                    }
-                }
-
-                counter
-            };
-
             let mut ordering = Ordering::Equal;
 
             if la == 0 {
@@ -222,9 +304,9 @@ pub fn compare_str<A: AsRef<str>, B: AsRef<str>>(a: A, b: B) -> Ordering {
 
                     if ordering == Ordering::Equal {
                         if let Some(c) = c1.next() {
-                            if c.is_ascii_digit() {
+                            if is_ascii_digit(c) {
                                 if let Some(cc) = c2.next() {
-                                    if cc.is_ascii_digit() {
+                                    if is_ascii_digit(cc) {
                                         ca = c;
                                         cb = cc;
                                     } else {
@@ -288,14 +370,14 @@ pub fn compare_str<A: AsRef<str>, B: AsRef<str>>(a: A, b: B) -> Ordering {
             match ca.cmp(&cb) {
                 Ordering::Equal => last_is_number = false,
                 Ordering::Greater => {
-                    return if last_is_number && (ca > (255 as char)) ^ (cb > (255 as char)) {
+                    return if last_is_number && (ca > 255) ^ (cb > 255) {
                         Ordering::Less
                     } else {
                         Ordering::Greater
                     };
                 },
                 Ordering::Less => {
-                    return if last_is_number && (ca > (255 as char)) ^ (cb > (255 as char)) {
+                    return if last_is_number && (ca > 255) ^ (cb > 255) {
                         Ordering::Greater
                     } else {
                         Ordering::Less
@@ -306,6 +388,40 @@ pub fn compare_str<A: AsRef<str>, B: AsRef<str>>(a: A, b: B) -> Ordering {
     }
 }
 
+const ZERO: u32 = '0' as u32;
+
+#[inline]
+fn is_ascii_digit(c: u32) -> bool {
+    (ZERO..=('9' as u32)).contains(&c)
+}
+
+/// Consume the remaining ASCII digits, and store the first unit after them.
+#[inline]
+fn consume_ascii_digit<I: Iterator<Item = u32>>(units: &mut I, store: &mut Option<u32>) -> usize {
+    let mut counter = 0;
+
+    for c in units.by_ref() {
+        if is_ascii_digit(c) {
+            counter += 1;
+        } else {
+            *store = Some(c);
+            break;
+        }
+    }
+
+    counter
+}
+
+/// Compare two strings case-insensitively.
+///
+/// Non-numeric characters are compared after being mapped to uppercase and then to lowercase, which is close to Unicode simple case folding (see `Collator::case_insensitive`). If two strings are equal in that way, the first case difference decides the order, so the result is still a total order. This is the same as comparing with `Collator::new().case_insensitive(true)`.
+#[inline]
+pub fn compare_str_case_insensitive<A: AsRef<str>, B: AsRef<str>>(a: A, b: B) -> Ordering {
+    CASE_INSENSITIVE.compare(a, b)
+}
+
+const CASE_INSENSITIVE: Collator = Collator::new().case_insensitive(true);
+
 // String-key sorting
 
 /// Sort a slice by a `str` key, but may not preserve the order of equal elements.
@@ -357,3 +473,199 @@ pub fn sort_str_slice<S: AsRef<str>>(slice: &mut [S]) {
 pub fn sort_str_slice_rev<S: AsRef<str>>(slice: &mut [S]) {
     slice.sort_unstable_by(|a, b| compare_str(b, a));
 }
+
+/// Sort a `str` slice case-insensitively.
+#[inline]
+pub fn sort_str_slice_case_insensitive<S: AsRef<str>>(slice: &mut [S]) {
+    slice.sort_unstable_by(|a, b| compare_str_case_insensitive(a, b));
+}
+
+// Partial sorting
+
+/// Reorder a `str` slice such that the element at `index` is at its final sorted position, like `slice::select_nth_unstable_by` with `compare_str`. No memory is allocated.
+///
+/// Returns the elements before `index`, which are not greater than it, the element at `index`, and the elements after `index`, which are not less than it.
+///
+/// # Panics
+///
+/// Panics when `index >= slice.len()`.
+#[inline]
+pub fn select_nth_str<S: AsRef<str>>(
+    slice: &mut [S],
+    index: usize,
+) -> (&mut [S], &mut S, &mut [S]) {
+    slice.select_nth_unstable_by(index, |a, b| compare_str(a, b))
+}
+
+/// Sort only the `k` least elements of a `str` slice into `slice[..k]`. The order of the rest is unspecified. No memory is allocated.
+///
+/// If `k` is not less than the length of the slice, the whole slice is sorted.
+#[inline]
+pub fn partial_sort_str_slice<S: AsRef<str>>(slice: &mut [S], k: usize) {
+    partial_sort_by(slice, k, |a, b| compare_str(a, b));
+}
+
+/// Move the `k` greatest elements of a slice by a `str` key to the front in descending order, and return them. The order of the rest is unspecified. No memory is allocated.
+///
+/// If `k` is not less than the length of the slice, the whole slice is reversely sorted.
+///
+/// ```rust
+/// let mut names = ["shot-2", "shot-11", "shot-1", "shot-10", "shot-3"];
+///
+/// let latest = alphanumeric_sort::top_k_by_str_key(&mut names, 2, |s| *s);
+///
+/// assert_eq!(["shot-11", "shot-10"], latest);
+/// ```
+#[inline]
+pub fn top_k_by_str_key<A, T: ?Sized + AsRef<str>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    k: usize,
+    mut f: F,
+) -> &mut [A] {
+    let k = k.min(slice.len());
+
+    partial_sort_by(slice, k, |a, b| compare_str(f(b), f(a)));
+
+    &mut slice[..k]
+}
+
+// Binary searching
+
+/// Binary search a `str` slice sorted by `sort_str_slice` for a string, like `slice::binary_search_by` with `compare_str`.
+///
+/// Returns `Ok` with the index of the string if it is found, or `Err` with the index where it can be inserted to keep the slice sorted.
+#[inline]
+pub fn binary_search_str<S: AsRef<str>, T: ?Sized + AsRef<str>>(
+    slice: &[S],
+    s: &T,
+) -> Result<usize, usize> {
+    let s = s.as_ref();
+
+    slice.binary_search_by(|e| compare_str(e, s))
+}
+
+/// Binary search a slice sorted by `sort_slice_by_str_key` (or its unstable variant) for a `str` key.
+///
+/// Returns `Ok` with the index of an element whose key equals `key` if there is one, or `Err` with the index where an element with the key can be inserted to keep the slice sorted.
+#[inline]
+pub fn binary_search_by_str_key<
+    A,
+    K: ?Sized + AsRef<str>,
+    T: ?Sized + AsRef<str>,
+    F: FnMut(&A) -> &T,
+>(
+    slice: &[A],
+    key: &K,
+    mut f: F,
+) -> Result<usize, usize> {
+    let key = key.as_ref();
+
+    slice.binary_search_by(|e| compare_str(f(e), key))
+}
+
+/// Get the count of the elements of a `str` slice sorted by `sort_str_slice` which are less than a string, which is the index of the first element not less than it.
+#[inline]
+pub fn partition_point_str<S: AsRef<str>, T: ?Sized + AsRef<str>>(slice: &[S], s: &T) -> usize {
+    let s = s.as_ref();
+
+    slice.partition_point(|e| compare_str(e, s) == Ordering::Less)
+}
+
+/// Insert a string into a `Vec` sorted by `sort_str_slice` and keep it sorted. Returns the index of the inserted string.
+///
+/// ```rust
+/// let mut names = vec!["shot-1", "shot-2", "shot-11"];
+///
+/// assert_eq!(2, alphanumeric_sort::insert_sorted_str(&mut names, "shot-10"));
+/// assert_eq!(["shot-1", "shot-2", "shot-10", "shot-11"], names.as_slice());
+/// ```
+#[inline]
+pub fn insert_sorted_str<S: AsRef<str>>(vec: &mut Vec<S>, s: S) -> usize {
+    // after equal elements, like pushing to a stably sorted `Vec`
+    let index = vec.partition_point(|e| compare_str(e, &s) != Ordering::Greater);
+
+    vec.insert(index, s);
+
+    index
+}
+
+#[inline]
+pub(crate) fn partial_sort_by<A>(
+    slice: &mut [A],
+    k: usize,
+    mut compare: impl FnMut(&A, &A) -> Ordering,
+) {
+    if k == 0 {
+        return;
+    }
+
+    if k < slice.len() {
+        // the elements before `k - 1` are not greater than it, so only they need to be sorted
+        slice.select_nth_unstable_by(k - 1, &mut compare);
+        slice[..k - 1].sort_unstable_by(compare);
+    } else {
+        slice.sort_unstable_by(compare);
+    }
+}
+
+#[inline]
+pub(crate) fn ref_index_str_pairs_to_ref_indexes_unstable(
+    mut ref_index_str_pairs: Vec<(usize, &str)>,
+) -> Vec<usize> {
+    ref_index_str_pairs.sort_unstable_by(|a, b| compare_str(a.1, b.1));
+
+    ref_index_str_pairs_to_ref_indexes_inner(ref_index_str_pairs)
+}
+
+#[inline]
+pub(crate) fn ref_index_str_pairs_to_ref_indexes(
+    mut ref_index_str_pairs: Vec<(usize, &str)>,
+) -> Vec<usize> {
+    ref_index_str_pairs.sort_by(|a, b| compare_str(a.1, b.1));
+
+    ref_index_str_pairs_to_ref_indexes_inner(ref_index_str_pairs)
+}
+
+#[inline]
+pub(crate) fn ref_index_str_pairs_to_ref_indexes_rev_unstable(
+    mut ref_index_str_pairs: Vec<(usize, &str)>,
+) -> Vec<usize> {
+    ref_index_str_pairs.sort_unstable_by(|a, b| compare_str(b.1, a.1));
+
+    ref_index_str_pairs_to_ref_indexes_inner(ref_index_str_pairs)
+}
+
+#[inline]
+pub(crate) fn ref_index_str_pairs_to_ref_indexes_rev(
+    mut ref_index_str_pairs: Vec<(usize, &str)>,
+) -> Vec<usize> {
+    ref_index_str_pairs.sort_by(|a, b| compare_str(b.1, a.1));
+
+    ref_index_str_pairs_to_ref_indexes_inner(ref_index_str_pairs)
+}
+
+#[inline]
+pub(crate) fn ref_index_str_pairs_to_ref_indexes_inner(
+    ref_index_str_pairs: Vec<(usize, &str)>,
+) -> Vec<usize> {
+    ref_index_str_pairs.into_iter().map(|(i, _)| i).collect()
+}
+
+#[inline]
+pub(crate) fn sort_slice_ref_indexes<S>(slice: &mut [S], mut permutation: Vec<usize>) {
+    // Cycle Decomposition
+    for i in 0..permutation.len() {
+        let mut current = i;
+
+        while permutation[current] != i {
+            let next = permutation[current];
+
+            slice.swap(current, next);
+
+            permutation[current] = current;
+            current = next;
+        }
+
+        permutation[current] = current;
+    }
+}
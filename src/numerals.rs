@@ -0,0 +1,206 @@
+/// The digit zeros of all the Unicode `Nd` (decimal number) blocks, as of Unicode 15.0. Every block is made up of ten consecutive code points, from zero to nine.
+const DECIMAL_DIGIT_ZEROS: [u32; 68] = [
+    0x0030, 0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6, 0x0B66, 0x0BE6, 0x0C66, 0x0CE6,
+    0x0D66, 0x0DE6, 0x0E50, 0x0ED0, 0x0F20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80,
+    0x1A90, 0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0,
+    0xFF10, 0x104A0, 0x10D30, 0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450, 0x114D0,
+    0x11650, 0x116C0, 0x11730, 0x118E0, 0x11950, 0x11C50, 0x11D50, 0x11DA0, 0x11F50, 0x16A60,
+    0x16AC0, 0x16B50, 0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0, 0x1E4F0,
+    0x1E950, 0x1FBF0,
+];
+
+/// Get the digit zero of the block which `c` belongs to, if `c` is a Unicode decimal digit.
+#[inline]
+pub(crate) fn decimal_digit_zero(c: char) -> Option<u32> {
+    if c.is_ascii() {
+        return if c.is_ascii_digit() { Some('0' as u32) } else { None };
+    }
+
+    let c = c as u32;
+
+    let zero = match DECIMAL_DIGIT_ZEROS.binary_search(&c) {
+        Ok(i) => return Some(DECIMAL_DIGIT_ZEROS[i]),
+        Err(0) => return None,
+        Err(i) => DECIMAL_DIGIT_ZEROS[i - 1],
+    };
+
+    if c - zero < 10 {
+        Some(zero)
+    } else {
+        None
+    }
+}
+
+/// Whether `c` can separate groups of digits, like the comma in `"1,200"`.
+#[inline]
+pub(crate) fn is_digit_separator(c: char) -> bool {
+    matches!(c, ',' | '_' | '\'' | '\u{2009}')
+}
+
+/// Get the value of a Chinese or Japanese numeral character. Digits are mapped to `0` to `9`, and multipliers (e.g. `十`, `万`) are mapped to their own values.
+#[inline]
+fn cjk_numeral_value(c: char) -> Option<u128> {
+    let v = match c {
+        '〇' | '零' => 0,
+        '一' => 1,
+        '二' | '两' | '兩' => 2,
+        '三' => 3,
+        '四' => 4,
+        '五' => 5,
+        '六' => 6,
+        '七' => 7,
+        '八' => 8,
+        '九' => 9,
+        '十' => 10,
+        '百' => 100,
+        '千' => 1000,
+        '万' | '萬' => 10000,
+        '亿' | '億' => 100000000,
+        _ => return None,
+    };
+
+    Some(v)
+}
+
+/// Whether `c` is a Chinese or Japanese numeral character.
+#[inline]
+pub(crate) fn is_cjk_numeral(c: char) -> bool {
+    cjk_numeral_value(c).is_some()
+}
+
+/// Parse the Chinese or Japanese numeral characters at the start of `s`.
+///
+/// Return the numeric value (saturated to `u128::MAX`), the count of the leading zeros and the length in bytes of the numeral characters. A sequence without any multiplier, such as `"二〇二三"`, is read digit by digit. Only such a sequence can have leading zeros.
+pub(crate) fn parse_cjk_numerals(s: &str) -> (u128, usize, usize) {
+    let end =
+        s.char_indices().find(|(_, c)| !is_cjk_numeral(*c)).map(|(i, _)| i).unwrap_or(s.len());
+
+    let s = &s[..end];
+
+    if s.chars().all(|c| matches!(cjk_numeral_value(c), Some(v) if v < 10)) {
+        let mut zeros = 0;
+        let mut value = 0u128;
+
+        for c in s.chars() {
+            let v = cjk_numeral_value(c).unwrap();
+
+            if value == 0 && v == 0 {
+                zeros += 1;
+            } else {
+                value = value.saturating_mul(10).saturating_add(v);
+            }
+        }
+
+        return (value, zeros, end);
+    }
+
+    // e.g. 十二 = 12, 一百零三 = 103, 三万五千 = 35000, 一亿二千万 = 120000000
+    let mut total = 0u128;
+    let mut section = 0u128;
+    let mut digit: Option<u128> = None;
+    let mut last_big_unit = 0u128;
+
+    for c in s.chars() {
+        let v = cjk_numeral_value(c).unwrap();
+
+        if v < 10 {
+            // a zero only marks a skipped position
+            digit = if v == 0 { None } else { Some(v) };
+        } else if v < 10000 {
+            section = section.saturating_add(digit.take().unwrap_or(1).saturating_mul(v));
+        } else {
+            let mut s = section.saturating_add(digit.take().unwrap_or(0));
+
+            if s == 0 && total == 0 {
+                s = 1;
+            }
+
+            if v > last_big_unit {
+                total = total.saturating_add(s).saturating_mul(v);
+                last_big_unit = v;
+            } else {
+                total = total.saturating_add(s.saturating_mul(v));
+            }
+
+            section = 0;
+        }
+    }
+
+    (total.saturating_add(section).saturating_add(digit.unwrap_or(0)), 0, end)
+}
+
+const ROMAN_NUMERALS: [(u128, &[u8]); 13] = [
+    (1000, b"M"),
+    (900, b"CM"),
+    (500, b"D"),
+    (400, b"CD"),
+    (100, b"C"),
+    (90, b"XC"),
+    (50, b"L"),
+    (40, b"XL"),
+    (10, b"X"),
+    (9, b"IX"),
+    (5, b"V"),
+    (4, b"IV"),
+    (1, b"I"),
+];
+
+/// Whether `c` is a letter used by Roman numerals.
+#[inline]
+pub(crate) fn is_roman_numeral(c: char) -> bool {
+    matches!(c, 'I' | 'V' | 'X' | 'L' | 'C' | 'D' | 'M' | 'i' | 'v' | 'x' | 'l' | 'c' | 'd' | 'm')
+}
+
+/// Parse a whole string as a Roman numeral from `I` to `MMMCMXCIX`.
+///
+/// Only the canonical form written entirely in uppercase or entirely in lowercase is accepted, so `"IIII"`, `"IC"` and `"Iv"` are not Roman numerals.
+pub(crate) fn parse_roman_numeral(s: &str) -> Option<u128> {
+    let bytes = s.as_bytes();
+
+    // the longest one is MMMDCCCLXXXVIII
+    if bytes.is_empty() || bytes.len() > 15 {
+        return None;
+    }
+
+    let upper = bytes.iter().all(|b| b"IVXLCDM".contains(b));
+    let lower = bytes.iter().all(|b| b"ivxlcdm".contains(b));
+
+    if !upper && !lower {
+        return None;
+    }
+
+    let mut value = 0;
+    let mut rest = bytes;
+
+    for (v, symbol) in ROMAN_NUMERALS.iter() {
+        while rest.len() >= symbol.len() && rest[..symbol.len()].eq_ignore_ascii_case(symbol) {
+            value += v;
+            rest = &rest[symbol.len()..];
+        }
+    }
+
+    if !rest.is_empty() {
+        return None;
+    }
+
+    // reject the non-canonical forms like "IXI" (10) by writing the value back
+    let mut remaining = value;
+    let mut rest = bytes;
+
+    for (v, symbol) in ROMAN_NUMERALS.iter() {
+        while remaining >= *v {
+            if rest.len() < symbol.len() || !rest[..symbol.len()].eq_ignore_ascii_case(symbol) {
+                return None;
+            }
+
+            remaining -= v;
+            rest = &rest[symbol.len()..];
+        }
+    }
+
+    if rest.is_empty() && value < 4000 {
+        Some(value)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,419 @@
+use std::{
+    ffi::{CStr, OsStr},
+    path::Path,
+};
+
+use rayon::slice::ParallelSliceMut;
+
+use crate::{
+    compare_str, sort_slice_ref_indexes,
+    std_functions::{compare_c_str_fallback, compare_os_str_fallback},
+};
+
+// Parallel string-key sorting
+
+/// Sort a slice by a `str` key in parallel, but may not preserve the order of equal elements.
+///
+/// The keys are taken once and sorted with their indexes in parallel, and then the slice is rearranged in place, so neither the elements nor the key function have to be thread-safe.
+#[inline]
+pub fn par_sort_slice_unstable_by_str_key<A, T: ?Sized + AsRef<str>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    f: F,
+) {
+    par_sort_slice_by_str_key_inner(slice, f, |pairs| {
+        pairs.par_sort_unstable_by(|a, b| compare_str(a.1, b.1))
+    })
+}
+
+/// Sort a slice by a `str` key in parallel.
+///
+/// The keys are taken once and sorted with their indexes in parallel, and then the slice is rearranged in place, so neither the elements nor the key function have to be thread-safe.
+#[inline]
+pub fn par_sort_slice_by_str_key<A, T: ?Sized + AsRef<str>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    f: F,
+) {
+    par_sort_slice_by_str_key_inner(slice, f, |pairs| {
+        pairs.par_sort_by(|a, b| compare_str(a.1, b.1))
+    })
+}
+
+/// Reversely sort a slice by a `str` key in parallel, but may not preserve the order of equal elements.
+///
+/// The keys are taken once and sorted with their indexes in parallel, and then the slice is rearranged in place, so neither the elements nor the key function have to be thread-safe.
+#[inline]
+pub fn par_sort_slice_rev_unstable_by_str_key<A, T: ?Sized + AsRef<str>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    f: F,
+) {
+    par_sort_slice_by_str_key_inner(slice, f, |pairs| {
+        pairs.par_sort_unstable_by(|a, b| compare_str(b.1, a.1))
+    })
+}
+
+/// Reversely sort a slice by a `str` key in parallel.
+///
+/// The keys are taken once and sorted with their indexes in parallel, and then the slice is rearranged in place, so neither the elements nor the key function have to be thread-safe.
+#[inline]
+pub fn par_sort_slice_rev_by_str_key<A, T: ?Sized + AsRef<str>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    f: F,
+) {
+    par_sort_slice_by_str_key_inner(slice, f, |pairs| {
+        pairs.par_sort_by(|a, b| compare_str(b.1, a.1))
+    })
+}
+
+fn par_sort_slice_by_str_key_inner<A, T: ?Sized + AsRef<str>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    mut f: F,
+    sort: impl Fn(&mut [(usize, &str)]),
+) {
+    let mut ref_index_str_pairs: Vec<(usize, &str)> =
+        slice.iter().enumerate().map(|(i, p)| (i, f(p).as_ref())).collect();
+
+    sort(&mut ref_index_str_pairs);
+
+    let ref_indexes = ref_index_str_pairs.into_iter().map(|(i, _)| i).collect();
+
+    sort_slice_ref_indexes(slice, ref_indexes);
+}
+
+// Parallel OsStr-key sorting
+
+/// Sort a slice by an `OsStr` key in parallel, but may not preserve the order of equal elements.
+///
+/// The keys are compared in the same way as `compare_os_str`. As with `sort_slice_by_os_str_key`, if all the keys are valid UTF-8, they are compared as `str` without checking them again in every comparison. The keys are taken once and sorted with their indexes in parallel, and then the slice is rearranged in place, so neither the elements nor the key function have to be thread-safe.
+#[inline]
+pub fn par_sort_slice_unstable_by_os_str_key<A, T: ?Sized + AsRef<OsStr>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    f: F,
+) {
+    par_sort_slice_by_os_str_key_inner(
+        slice,
+        f,
+        |pairs| pairs.par_sort_unstable_by(|a, b| compare_str(a.1, b.1)),
+        |pairs| pairs.par_sort_unstable_by(|a, b| compare_os_str_fallback(a.1, b.1)),
+    )
+}
+
+/// Sort a slice by an `OsStr` key in parallel.
+///
+/// The keys are compared in the same way as `compare_os_str`. As with `sort_slice_by_os_str_key`, if all the keys are valid UTF-8, they are compared as `str` without checking them again in every comparison. The keys are taken once and sorted with their indexes in parallel, and then the slice is rearranged in place, so neither the elements nor the key function have to be thread-safe.
+#[inline]
+pub fn par_sort_slice_by_os_str_key<A, T: ?Sized + AsRef<OsStr>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    f: F,
+) {
+    par_sort_slice_by_os_str_key_inner(
+        slice,
+        f,
+        |pairs| pairs.par_sort_by(|a, b| compare_str(a.1, b.1)),
+        |pairs| pairs.par_sort_by(|a, b| compare_os_str_fallback(a.1, b.1)),
+    )
+}
+
+/// Reversely sort a slice by an `OsStr` key in parallel, but may not preserve the order of equal elements.
+///
+/// The keys are compared in the same way as `compare_os_str`. As with `sort_slice_by_os_str_key`, if all the keys are valid UTF-8, they are compared as `str` without checking them again in every comparison. The keys are taken once and sorted with their indexes in parallel, and then the slice is rearranged in place, so neither the elements nor the key function have to be thread-safe.
+#[inline]
+pub fn par_sort_slice_rev_unstable_by_os_str_key<
+    A,
+    T: ?Sized + AsRef<OsStr>,
+    F: FnMut(&A) -> &T,
+>(
+    slice: &mut [A],
+    f: F,
+) {
+    par_sort_slice_by_os_str_key_inner(
+        slice,
+        f,
+        |pairs| pairs.par_sort_unstable_by(|a, b| compare_str(b.1, a.1)),
+        |pairs| pairs.par_sort_unstable_by(|a, b| compare_os_str_fallback(b.1, a.1)),
+    )
+}
+
+/// Reversely sort a slice by an `OsStr` key in parallel.
+///
+/// The keys are compared in the same way as `compare_os_str`. As with `sort_slice_by_os_str_key`, if all the keys are valid UTF-8, they are compared as `str` without checking them again in every comparison. The keys are taken once and sorted with their indexes in parallel, and then the slice is rearranged in place, so neither the elements nor the key function have to be thread-safe.
+#[inline]
+pub fn par_sort_slice_rev_by_os_str_key<A, T: ?Sized + AsRef<OsStr>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    f: F,
+) {
+    par_sort_slice_by_os_str_key_inner(
+        slice,
+        f,
+        |pairs| pairs.par_sort_by(|a, b| compare_str(b.1, a.1)),
+        |pairs| pairs.par_sort_by(|a, b| compare_os_str_fallback(b.1, a.1)),
+    )
+}
+
+fn par_sort_slice_by_os_str_key_inner<A, T: ?Sized + AsRef<OsStr>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    mut f: F,
+    sort: impl Fn(&mut [(usize, &str)]),
+    fallback: impl Fn(&mut [(usize, &OsStr)]),
+) {
+    let ref_index_os_str_pairs: Vec<(usize, &OsStr)> =
+        slice.iter().enumerate().map(|(i, p)| (i, f(p).as_ref())).collect();
+
+    let ref_indexes =
+        par_ref_index_pairs_to_ref_indexes(ref_index_os_str_pairs, |s| s.to_str(), sort, fallback);
+
+    sort_slice_ref_indexes(slice, ref_indexes);
+}
+
+// Parallel CStr-key sorting
+
+/// Sort a slice by a `CStr` key in parallel, but may not preserve the order of equal elements.
+///
+/// The keys are compared in the same way as `compare_c_str`. As with `sort_slice_by_c_str_key`, if all the keys are valid UTF-8, they are compared as `str` without checking them again in every comparison. The keys are taken once and sorted with their indexes in parallel, and then the slice is rearranged in place, so neither the elements nor the key function have to be thread-safe.
+#[inline]
+pub fn par_sort_slice_unstable_by_c_str_key<A, T: ?Sized + AsRef<CStr>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    f: F,
+) {
+    par_sort_slice_by_c_str_key_inner(
+        slice,
+        f,
+        |pairs| pairs.par_sort_unstable_by(|a, b| compare_str(a.1, b.1)),
+        |pairs| pairs.par_sort_unstable_by(|a, b| compare_c_str_fallback(a.1, b.1)),
+    )
+}
+
+/// Sort a slice by a `CStr` key in parallel.
+///
+/// The keys are compared in the same way as `compare_c_str`. As with `sort_slice_by_c_str_key`, if all the keys are valid UTF-8, they are compared as `str` without checking them again in every comparison. The keys are taken once and sorted with their indexes in parallel, and then the slice is rearranged in place, so neither the elements nor the key function have to be thread-safe.
+#[inline]
+pub fn par_sort_slice_by_c_str_key<A, T: ?Sized + AsRef<CStr>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    f: F,
+) {
+    par_sort_slice_by_c_str_key_inner(
+        slice,
+        f,
+        |pairs| pairs.par_sort_by(|a, b| compare_str(a.1, b.1)),
+        |pairs| pairs.par_sort_by(|a, b| compare_c_str_fallback(a.1, b.1)),
+    )
+}
+
+/// Reversely sort a slice by a `CStr` key in parallel, but may not preserve the order of equal elements.
+///
+/// The keys are compared in the same way as `compare_c_str`. As with `sort_slice_by_c_str_key`, if all the keys are valid UTF-8, they are compared as `str` without checking them again in every comparison. The keys are taken once and sorted with their indexes in parallel, and then the slice is rearranged in place, so neither the elements nor the key function have to be thread-safe.
+#[inline]
+pub fn par_sort_slice_rev_unstable_by_c_str_key<A, T: ?Sized + AsRef<CStr>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    f: F,
+) {
+    par_sort_slice_by_c_str_key_inner(
+        slice,
+        f,
+        |pairs| pairs.par_sort_unstable_by(|a, b| compare_str(b.1, a.1)),
+        |pairs| pairs.par_sort_unstable_by(|a, b| compare_c_str_fallback(b.1, a.1)),
+    )
+}
+
+/// Reversely sort a slice by a `CStr` key in parallel.
+///
+/// The keys are compared in the same way as `compare_c_str`. As with `sort_slice_by_c_str_key`, if all the keys are valid UTF-8, they are compared as `str` without checking them again in every comparison. The keys are taken once and sorted with their indexes in parallel, and then the slice is rearranged in place, so neither the elements nor the key function have to be thread-safe.
+#[inline]
+pub fn par_sort_slice_rev_by_c_str_key<A, T: ?Sized + AsRef<CStr>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    f: F,
+) {
+    par_sort_slice_by_c_str_key_inner(
+        slice,
+        f,
+        |pairs| pairs.par_sort_by(|a, b| compare_str(b.1, a.1)),
+        |pairs| pairs.par_sort_by(|a, b| compare_c_str_fallback(b.1, a.1)),
+    )
+}
+
+fn par_sort_slice_by_c_str_key_inner<A, T: ?Sized + AsRef<CStr>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    mut f: F,
+    sort: impl Fn(&mut [(usize, &str)]),
+    fallback: impl Fn(&mut [(usize, &CStr)]),
+) {
+    let ref_index_c_str_pairs: Vec<(usize, &CStr)> =
+        slice.iter().enumerate().map(|(i, p)| (i, f(p).as_ref())).collect();
+
+    let ref_indexes = par_ref_index_pairs_to_ref_indexes(
+        ref_index_c_str_pairs,
+        |s| s.to_str().ok(),
+        sort,
+        fallback,
+    );
+
+    sort_slice_ref_indexes(slice, ref_indexes);
+}
+
+// Parallel path-key sorting
+
+/// Sort a slice by a `Path` key in parallel, but may not preserve the order of equal elements.
+///
+/// The keys are compared in the same way as `compare_path`. As with `sort_slice_by_path_key`, if all the keys are valid UTF-8, they are compared as `str` without checking them again in every comparison. The keys are taken once and sorted with their indexes in parallel, and then the slice is rearranged in place, so neither the elements nor the key function have to be thread-safe.
+#[inline]
+pub fn par_sort_slice_unstable_by_path_key<A, T: ?Sized + AsRef<Path>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    f: F,
+) {
+    par_sort_slice_by_path_key_inner(
+        slice,
+        f,
+        |pairs| pairs.par_sort_unstable_by(|a, b| compare_str(a.1, b.1)),
+        |pairs| pairs.par_sort_unstable_by(|a, b| compare_os_str_fallback(a.1, b.1)),
+    )
+}
+
+/// Sort a slice by a `Path` key in parallel.
+///
+/// The keys are compared in the same way as `compare_path`. As with `sort_slice_by_path_key`, if all the keys are valid UTF-8, they are compared as `str` without checking them again in every comparison. The keys are taken once and sorted with their indexes in parallel, and then the slice is rearranged in place, so neither the elements nor the key function have to be thread-safe.
+#[inline]
+pub fn par_sort_slice_by_path_key<A, T: ?Sized + AsRef<Path>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    f: F,
+) {
+    par_sort_slice_by_path_key_inner(
+        slice,
+        f,
+        |pairs| pairs.par_sort_by(|a, b| compare_str(a.1, b.1)),
+        |pairs| pairs.par_sort_by(|a, b| compare_os_str_fallback(a.1, b.1)),
+    )
+}
+
+/// Reversely sort a slice by a `Path` key in parallel, but may not preserve the order of equal elements.
+///
+/// The keys are compared in the same way as `compare_path`. As with `sort_slice_by_path_key`, if all the keys are valid UTF-8, they are compared as `str` without checking them again in every comparison. The keys are taken once and sorted with their indexes in parallel, and then the slice is rearranged in place, so neither the elements nor the key function have to be thread-safe.
+#[inline]
+pub fn par_sort_slice_rev_unstable_by_path_key<A, T: ?Sized + AsRef<Path>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    f: F,
+) {
+    par_sort_slice_by_path_key_inner(
+        slice,
+        f,
+        |pairs| pairs.par_sort_unstable_by(|a, b| compare_str(b.1, a.1)),
+        |pairs| pairs.par_sort_unstable_by(|a, b| compare_os_str_fallback(b.1, a.1)),
+    )
+}
+
+/// Reversely sort a slice by a `Path` key in parallel.
+///
+/// The keys are compared in the same way as `compare_path`. As with `sort_slice_by_path_key`, if all the keys are valid UTF-8, they are compared as `str` without checking them again in every comparison. The keys are taken once and sorted with their indexes in parallel, and then the slice is rearranged in place, so neither the elements nor the key function have to be thread-safe.
+#[inline]
+pub fn par_sort_slice_rev_by_path_key<A, T: ?Sized + AsRef<Path>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    f: F,
+) {
+    par_sort_slice_by_path_key_inner(
+        slice,
+        f,
+        |pairs| pairs.par_sort_by(|a, b| compare_str(b.1, a.1)),
+        |pairs| pairs.par_sort_by(|a, b| compare_os_str_fallback(b.1, a.1)),
+    )
+}
+
+fn par_sort_slice_by_path_key_inner<A, T: ?Sized + AsRef<Path>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    mut f: F,
+    sort: impl Fn(&mut [(usize, &str)]),
+    fallback: impl Fn(&mut [(usize, &OsStr)]),
+) {
+    let ref_index_os_str_pairs: Vec<(usize, &OsStr)> =
+        slice.iter().enumerate().map(|(i, p)| (i, f(p).as_ref().as_os_str())).collect();
+
+    let ref_indexes =
+        par_ref_index_pairs_to_ref_indexes(ref_index_os_str_pairs, |s| s.to_str(), sort, fallback);
+
+    sort_slice_ref_indexes(slice, ref_indexes);
+}
+
+/// Sort the index-key pairs as `str` if every key is valid UTF-8, or with the fallback otherwise, and get the sorted indexes.
+fn par_ref_index_pairs_to_ref_indexes<'a, K: Copy>(
+    ref_index_key_pairs: Vec<(usize, K)>,
+    to_str: impl Fn(K) -> Option<&'a str>,
+    sort: impl Fn(&mut [(usize, &'a str)]),
+    fallback: impl Fn(&mut [(usize, K)]),
+) -> Vec<usize> {
+    let ref_index_str_pairs: Option<Vec<(usize, &str)>> =
+        ref_index_key_pairs.iter().map(|&(i, k)| to_str(k).map(|s| (i, s))).collect();
+
+    match ref_index_str_pairs {
+        Some(mut ref_index_str_pairs) => {
+            sort(&mut ref_index_str_pairs);
+
+            ref_index_str_pairs.into_iter().map(|(i, _)| i).collect()
+        },
+        None => {
+            let mut ref_index_key_pairs = ref_index_key_pairs;
+
+            fallback(&mut ref_index_key_pairs);
+
+            ref_index_key_pairs.into_iter().map(|(i, _)| i).collect()
+        },
+    }
+}
+
+// Direct parallel slice sorting
+
+/// Sort a `str` slice in parallel.
+#[inline]
+pub fn par_sort_str_slice<S: AsRef<str> + Send + Sync>(slice: &mut [S]) {
+    slice.par_sort_unstable_by(|a, b| compare_str(a, b));
+}
+
+/// Reversely sort a `str` slice in parallel.
+#[inline]
+pub fn par_sort_str_slice_rev<S: AsRef<str> + Send + Sync>(slice: &mut [S]) {
+    slice.par_sort_unstable_by(|a, b| compare_str(b, a));
+}
+
+/// Sort an `OsStr` slice in parallel.
+///
+/// The items are compared in the same way as `compare_os_str`, with the same UTF-8 prevalidation as `sort_os_str_slice`.
+#[inline]
+pub fn par_sort_os_str_slice<S: AsRef<OsStr>>(slice: &mut [S]) {
+    par_sort_slice_unstable_by_os_str_key(slice, |e| e.as_ref())
+}
+
+/// Reversely sort an `OsStr` slice in parallel.
+///
+/// The items are compared in the same way as `compare_os_str`, with the same UTF-8 prevalidation as `sort_os_str_slice_rev`.
+#[inline]
+pub fn par_sort_os_str_slice_rev<S: AsRef<OsStr>>(slice: &mut [S]) {
+    par_sort_slice_rev_unstable_by_os_str_key(slice, |e| e.as_ref())
+}
+
+/// Sort a `CStr` slice in parallel.
+///
+/// The items are compared in the same way as `compare_c_str`, with the same UTF-8 prevalidation as `sort_c_str_slice`.
+#[inline]
+pub fn par_sort_c_str_slice<S: AsRef<CStr>>(slice: &mut [S]) {
+    par_sort_slice_unstable_by_c_str_key(slice, |e| e.as_ref())
+}
+
+/// Reversely sort a `CStr` slice in parallel.
+///
+/// The items are compared in the same way as `compare_c_str`, with the same UTF-8 prevalidation as `sort_c_str_slice_rev`.
+#[inline]
+pub fn par_sort_c_str_slice_rev<S: AsRef<CStr>>(slice: &mut [S]) {
+    par_sort_slice_rev_unstable_by_c_str_key(slice, |e| e.as_ref())
+}
+
+/// Sort a `Path` slice in parallel.
+///
+/// The paths are compared in the same way as `compare_path`, with the same UTF-8 prevalidation as `sort_path_slice`.
+#[inline]
+pub fn par_sort_path_slice<P: AsRef<Path>>(slice: &mut [P]) {
+    par_sort_slice_unstable_by_path_key(slice, |e| e.as_ref())
+}
+
+/// Reversely sort a `Path` slice in parallel.
+///
+/// The paths are compared in the same way as `compare_path`, with the same UTF-8 prevalidation as `sort_path_slice_rev`.
+#[inline]
+pub fn par_sort_path_slice_rev<P: AsRef<Path>>(slice: &mut [P]) {
+    par_sort_slice_rev_unstable_by_path_key(slice, |e| e.as_ref())
+}
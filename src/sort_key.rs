@@ -0,0 +1,177 @@
+use alloc::vec::Vec;
+
+use crate::{chunks, sort_slice_ref_indexes, Chunk};
+
+/// The end of the main part, which is less than the first byte of any token.
+const END: u8 = 0x00;
+/// The first byte of a digit sequence, which sits between the encodings of `'/'` and `':'`.
+const NUMBER: u8 = b'0' + 1;
+/// The first byte of a character greater than U+00FF right after a digit sequence.
+const AFTER_NUMBER_HIGH: u8 = 0x01;
+/// The first byte of a character not greater than U+00FF right after a digit sequence.
+const AFTER_NUMBER_LOW: u8 = 0x02;
+
+/// Create a binary sort key of a string, such that comparing two keys bytewise (like `memcmp` or `<[u8]>::cmp`) gives the same result as `compare_str` on the strings.
+///
+/// The keys can be stored in databases (e.g. as `BLOB` columns or as keys of an ordered key-value store) to get the alphanumeric order from their byte ordering. The key format is stable within a major version of this crate. A key cannot be decoded back into the string.
+///
+/// ```rust
+/// let mut keys =
+///     ["shot-2", "shot-1", "shot-11"].map(alphanumeric_sort::sort_key);
+///
+/// keys.sort();
+///
+/// assert_eq!(alphanumeric_sort::sort_key("shot-1"), keys[0]);
+/// assert_eq!(alphanumeric_sort::sort_key("shot-2"), keys[1]);
+/// assert_eq!(alphanumeric_sort::sort_key("shot-11"), keys[2]);
+/// ```
+#[inline]
+pub fn sort_key<S: AsRef<str>>(s: S) -> Vec<u8> {
+    let s = s.as_ref();
+
+    let mut buffer = Vec::with_capacity(s.len() + 2);
+
+    encode_into(s, &mut buffer);
+
+    buffer
+}
+
+/// Append the binary sort key of a string to a buffer. See [`sort_key`] for the properties of the key.
+///
+/// Existing contents of the buffer are kept, so a buffer can be reused by clearing it first, or a key can be appended after a prefix which is compared first.
+pub fn encode_into<S: AsRef<str>>(s: S, buffer: &mut Vec<u8>) {
+    let s = s.as_ref();
+
+    // main part: numbers by their values, and characters by their Unicode scalar values
+    let mut after_number = false;
+
+    for chunk in chunks(s) {
+        match chunk {
+            Chunk::Number {
+                value_digits, ..
+            } => {
+                buffer.push(NUMBER);
+                push_length(buffer, value_digits.len());
+                buffer.extend_from_slice(value_digits.as_bytes());
+
+                after_number = true;
+            },
+            Chunk::Text(text) => {
+                let mut text = text.as_bytes();
+
+                if after_number {
+                    // the ordering of characters on different sides of U+00FF is reversed right after a number
+                    let first = text[0];
+                    let width = utf8_width(first);
+
+                    buffer.push(if width > 2 || first > 0xC3 {
+                        AFTER_NUMBER_HIGH
+                    } else {
+                        AFTER_NUMBER_LOW
+                    });
+                    buffer.extend_from_slice(&text[..width]);
+
+                    text = &text[width..];
+                }
+
+                for &b in text {
+                    if b < b'0' {
+                        // shift to keep END as the least byte
+                        buffer.push(b + 1);
+                    } else {
+                        // UTF-8 keeps the order of Unicode scalar values, and every character here is greater than '9'
+                        buffer.push(b);
+                    }
+                }
+
+                after_number = false;
+            },
+        }
+    }
+
+    // tie-breaker part: the counts of the leading zeros of the numbers, where trailing zero counts can be omitted
+    let main_end = buffer.len();
+
+    buffer.push(END);
+
+    let mut tie_breaker_end = main_end;
+
+    for chunk in chunks(s) {
+        if let Chunk::Number {
+            leading_zeros, ..
+        } = chunk
+        {
+            push_length(buffer, leading_zeros);
+
+            if leading_zeros > 0 {
+                tie_breaker_end = buffer.len();
+            }
+        }
+    }
+
+    buffer.truncate(tie_breaker_end);
+}
+
+/// Sort a `str` slice by creating the sort key of every element once, instead of scanning the strings again in every comparison.
+///
+/// The result is the same as that of `sort_str_slice`. It is faster for large slices (e.g. more than thousands of elements), at the cost of memory for the keys, which are a bit longer than the strings in total.
+///
+/// ```rust
+/// let mut names = ["shot-2", "shot-1", "shot-11"];
+///
+/// alphanumeric_sort::sort_str_slice_cached(&mut names);
+///
+/// assert_eq!(["shot-1", "shot-2", "shot-11"], names);
+/// ```
+pub fn sort_str_slice_cached<S: AsRef<str>>(slice: &mut [S]) {
+    let capacity = slice.iter().map(|s| s.as_ref().len() + 2).sum();
+
+    let mut buffer = Vec::with_capacity(capacity);
+    let mut ends = Vec::with_capacity(slice.len());
+
+    for s in slice.iter() {
+        encode_into(s, &mut buffer);
+
+        ends.push(buffer.len());
+    }
+
+    let mut start = 0;
+
+    let mut keys: Vec<(&[u8], usize)> = ends
+        .into_iter()
+        .enumerate()
+        .map(|(i, end)| {
+            let key = &buffer[start..end];
+
+            start = end;
+
+            (key, i)
+        })
+        .collect();
+
+    // keys are equal only if the strings are identical, so the order of equal keys does not matter
+    keys.sort_unstable_by_key(|(key, _)| *key);
+
+    sort_slice_ref_indexes(slice, keys.into_iter().map(|(_, i)| i).collect());
+}
+
+/// Push a length whose encoding keeps the numeric order and is self-delimiting: the count of the significant bytes, and then those bytes in big-endian.
+#[inline]
+fn push_length(buffer: &mut Vec<u8>, length: usize) {
+    let bytes = (length as u64).to_be_bytes();
+    let skip = (length as u64).leading_zeros() as usize / 8;
+
+    buffer.push((8 - skip) as u8);
+    buffer.extend_from_slice(&bytes[skip..]);
+}
+
+/// Get the width of a UTF-8 sequence by its first byte.
+#[inline]
+fn utf8_width(first: u8) -> usize {
+    match first {
+        0x00..=0x7F => 1,
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        _ => 4,
+    }
+}
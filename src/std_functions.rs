@@ -1,16 +1,24 @@
 use core::cmp::Ordering;
 use std::{
+    borrow::Cow,
     ffi::{CStr, OsStr},
+    fs::{FileType, Metadata},
     path::Path,
 };
 
-use crate::compare_str;
+use crate::{
+    bytes::Utf8Units, compare_str, compare_str_case_insensitive, compare_units, partial_sort_by,
+    ref_index_str_pairs_to_ref_indexes, ref_index_str_pairs_to_ref_indexes_inner,
+    ref_index_str_pairs_to_ref_indexes_rev, ref_index_str_pairs_to_ref_indexes_rev_unstable,
+    ref_index_str_pairs_to_ref_indexes_unstable, sort_slice_ref_indexes,
+};
 
 /// Compare two `OsStr`.
 ///
-/// The alphanumeric algorithm is used only when both values can be converted to UTF-8
-/// with `OsStr::to_str`. If either value cannot be converted, this falls back to the
-/// native `OsStr` ordering.
+/// If either value is not valid UTF-8, both values are compared by their bytes from
+/// `OsStr::as_encoded_bytes` with the same alphanumeric algorithm. ASCII digit runs
+/// are still compared by their numeric values, and a byte which is not a part of a
+/// character is greater than any character.
 #[inline]
 pub fn compare_os_str<A: AsRef<OsStr>, B: AsRef<OsStr>>(a: A, b: B) -> Ordering {
     let sa = match a.as_ref().to_str() {
@@ -31,15 +39,19 @@ pub fn compare_os_str<A: AsRef<OsStr>, B: AsRef<OsStr>>(a: A, b: B) -> Ordering
 }
 
 #[inline]
-fn compare_os_str_fallback<A: AsRef<OsStr>, B: AsRef<OsStr>>(a: A, b: B) -> Ordering {
-    a.as_ref().cmp(b.as_ref())
+pub(crate) fn compare_os_str_fallback<A: AsRef<OsStr>, B: AsRef<OsStr>>(a: A, b: B) -> Ordering {
+    compare_units(
+        Utf8Units::new(a.as_ref().as_encoded_bytes()),
+        Utf8Units::new(b.as_ref().as_encoded_bytes()),
+    )
 }
 
 /// Compare two `CStr`.
 ///
-/// The alphanumeric algorithm is used only when both values can be converted to UTF-8
-/// with `CStr::to_str`. If either value cannot be converted, this falls back to the
-/// native `CStr` ordering.
+/// If either value is not valid UTF-8, both values are compared by their bytes from
+/// `CStr::to_bytes` with the same alphanumeric algorithm. ASCII digit runs are still
+/// compared by their numeric values, and a byte which is not a part of a character is
+/// greater than any character.
 #[inline]
 pub fn compare_c_str<A: AsRef<CStr>, B: AsRef<CStr>>(a: A, b: B) -> Ordering {
     let sa = match a.as_ref().to_str() {
@@ -60,25 +72,47 @@ pub fn compare_c_str<A: AsRef<CStr>, B: AsRef<CStr>>(a: A, b: B) -> Ordering {
 }
 
 #[inline]
-fn compare_c_str_fallback<A: AsRef<CStr>, B: AsRef<CStr>>(a: A, b: B) -> Ordering {
-    a.as_ref().cmp(b.as_ref())
+pub(crate) fn compare_c_str_fallback<A: AsRef<CStr>, B: AsRef<CStr>>(a: A, b: B) -> Ordering {
+    compare_units(Utf8Units::new(a.as_ref().to_bytes()), Utf8Units::new(b.as_ref().to_bytes()))
 }
 
 /// Compare two `Path`.
 ///
-/// The alphanumeric algorithm is used only when both paths can be converted to UTF-8
-/// through their `OsStr` representations. If either path cannot be converted, this
-/// falls back to the native `OsStr` ordering.
+/// The paths are compared as their `OsStr` representations in the same way as
+/// `compare_os_str`.
 #[inline]
 pub fn compare_path<A: AsRef<Path>, B: AsRef<Path>>(a: A, b: B) -> Ordering {
     compare_os_str(a.as_ref(), b.as_ref())
 }
 
+/// Compare two `Path` component by component.
+///
+/// The paths are split by `Path::components`, and each pair of components is compared
+/// with `compare_os_str`. Unlike `compare_path`, separators never take part in the
+/// comparison, so the contents of a directory are always grouped together, e.g. `"a/b"`
+/// is less than `"a-1/x"`. A path whose components are a prefix of the other's is less.
+#[inline]
+pub fn compare_path_components<A: AsRef<Path>, B: AsRef<Path>>(a: A, b: B) -> Ordering {
+    let mut ca = a.as_ref().components();
+    let mut cb = b.as_ref().components();
+
+    loop {
+        match (ca.next(), cb.next()) {
+            (Some(xa), Some(xb)) => match compare_os_str(xa, xb) {
+                Ordering::Equal => (),
+                ordering => return ordering,
+            },
+            (Some(_), None) => return Ordering::Greater,
+            (None, Some(_)) => return Ordering::Less,
+            (None, None) => return Ordering::Equal,
+        }
+    }
+}
+
 /// Sort a slice by an `OsStr` key, but may not preserve the order of equal elements.
 ///
-/// The alphanumeric algorithm is used only if every key can be converted to UTF-8.
-/// If any key cannot be converted, the whole slice is sorted by native `OsStr` key
-/// ordering.
+/// The keys are compared in the same way as `compare_os_str`, so a key which is not valid
+/// UTF-8 does not change the order of the others.
 #[inline]
 pub fn sort_slice_unstable_by_os_str_key<A, T: ?Sized + AsRef<OsStr>, F: FnMut(&A) -> &T>(
     slice: &mut [A],
@@ -94,9 +128,8 @@ pub fn sort_slice_unstable_by_os_str_key<A, T: ?Sized + AsRef<OsStr>, F: FnMut(&
 
 /// Sort a slice by an `OsStr` key.
 ///
-/// The alphanumeric algorithm is used only if every key can be converted to UTF-8.
-/// If any key cannot be converted, the whole slice is sorted by native `OsStr` key
-/// ordering.
+/// The keys are compared in the same way as `compare_os_str`, so a key which is not valid
+/// UTF-8 does not change the order of the others.
 #[inline]
 pub fn sort_slice_by_os_str_key<A, T: ?Sized + AsRef<OsStr>, F: FnMut(&A) -> &T>(
     slice: &mut [A],
@@ -112,9 +145,8 @@ pub fn sort_slice_by_os_str_key<A, T: ?Sized + AsRef<OsStr>, F: FnMut(&A) -> &T>
 
 /// Reversely sort a slice by an `OsStr` key, but may not preserve the order of equal elements.
 ///
-/// The alphanumeric algorithm is used only if every key can be converted to UTF-8.
-/// If any key cannot be converted, the whole slice is sorted by native `OsStr` key
-/// ordering.
+/// The keys are compared in the same way as `compare_os_str`, so a key which is not valid
+/// UTF-8 does not change the order of the others.
 #[inline]
 pub fn sort_slice_rev_unstable_by_os_str_key<A, T: ?Sized + AsRef<OsStr>, F: FnMut(&A) -> &T>(
     slice: &mut [A],
@@ -130,9 +162,8 @@ pub fn sort_slice_rev_unstable_by_os_str_key<A, T: ?Sized + AsRef<OsStr>, F: FnM
 
 /// Reversely sort a slice by an `OsStr` key.
 ///
-/// The alphanumeric algorithm is used only if every key can be converted to UTF-8.
-/// If any key cannot be converted, the whole slice is sorted by native `OsStr` key
-/// ordering.
+/// The keys are compared in the same way as `compare_os_str`, so a key which is not valid
+/// UTF-8 does not change the order of the others.
 #[inline]
 pub fn sort_slice_rev_by_os_str_key<A, T: ?Sized + AsRef<OsStr>, F: FnMut(&A) -> &T>(
     slice: &mut [A],
@@ -215,9 +246,8 @@ fn sort_slice_rev_by_os_str_key_fallback<A, T: ?Sized + AsRef<OsStr>, F: FnMut(&
 
 /// Sort a slice by a `CStr` key, but may not preserve the order of equal elements.
 ///
-/// The alphanumeric algorithm is used only if every key can be converted to UTF-8.
-/// If any key cannot be converted, the whole slice is sorted by native `CStr` key
-/// ordering.
+/// The keys are compared in the same way as `compare_c_str`, so a key which is not valid
+/// UTF-8 does not change the order of the others.
 #[inline]
 pub fn sort_slice_unstable_by_c_str_key<A, T: ?Sized + AsRef<CStr>, F: FnMut(&A) -> &T>(
     slice: &mut [A],
@@ -233,9 +263,8 @@ pub fn sort_slice_unstable_by_c_str_key<A, T: ?Sized + AsRef<CStr>, F: FnMut(&A)
 
 /// Sort a slice by a `CStr` key.
 ///
-/// The alphanumeric algorithm is used only if every key can be converted to UTF-8.
-/// If any key cannot be converted, the whole slice is sorted by native `CStr` key
-/// ordering.
+/// The keys are compared in the same way as `compare_c_str`, so a key which is not valid
+/// UTF-8 does not change the order of the others.
 #[inline]
 pub fn sort_slice_by_c_str_key<A, T: ?Sized + AsRef<CStr>, F: FnMut(&A) -> &T>(
     slice: &mut [A],
@@ -251,9 +280,8 @@ pub fn sort_slice_by_c_str_key<A, T: ?Sized + AsRef<CStr>, F: FnMut(&A) -> &T>(
 
 /// Reversely sort a slice by a `CStr` key, but may not preserve the order of equal elements.
 ///
-/// The alphanumeric algorithm is used only if every key can be converted to UTF-8.
-/// If any key cannot be converted, the whole slice is sorted by native `CStr` key
-/// ordering.
+/// The keys are compared in the same way as `compare_c_str`, so a key which is not valid
+/// UTF-8 does not change the order of the others.
 #[inline]
 pub fn sort_slice_rev_unstable_by_c_str_key<A, T: ?Sized + AsRef<CStr>, F: FnMut(&A) -> &T>(
     slice: &mut [A],
@@ -269,9 +297,8 @@ pub fn sort_slice_rev_unstable_by_c_str_key<A, T: ?Sized + AsRef<CStr>, F: FnMut
 
 /// Reversely sort a slice by a `CStr` key.
 ///
-/// The alphanumeric algorithm is used only if every key can be converted to UTF-8.
-/// If any key cannot be converted, the whole slice is sorted by native `CStr` key
-/// ordering.
+/// The keys are compared in the same way as `compare_c_str`, so a key which is not valid
+/// UTF-8 does not change the order of the others.
 #[inline]
 pub fn sort_slice_rev_by_c_str_key<A, T: ?Sized + AsRef<CStr>, F: FnMut(&A) -> &T>(
     slice: &mut [A],
@@ -350,9 +377,8 @@ fn sort_slice_rev_by_c_str_key_fallback<A, T: ?Sized + AsRef<CStr>, F: FnMut(&A)
 
 /// Sort a slice by a `Path` key, but may not preserve the order of equal elements.
 ///
-/// The alphanumeric algorithm is used only if every key can be converted to UTF-8
-/// through its `OsStr` representation. If any key cannot be converted, the whole
-/// slice is sorted by native `OsStr` key ordering.
+/// The keys are compared in the same way as `compare_path`, so a key which is not valid
+/// UTF-8 does not change the order of the others.
 #[inline]
 pub fn sort_slice_unstable_by_path_key<A, T: ?Sized + AsRef<Path>, F: FnMut(&A) -> &T>(
     slice: &mut [A],
@@ -368,9 +394,8 @@ pub fn sort_slice_unstable_by_path_key<A, T: ?Sized + AsRef<Path>, F: FnMut(&A)
 
 /// Sort a slice by a `Path` key.
 ///
-/// The alphanumeric algorithm is used only if every key can be converted to UTF-8
-/// through its `OsStr` representation. If any key cannot be converted, the whole
-/// slice is sorted by native `OsStr` key ordering.
+/// The keys are compared in the same way as `compare_path`, so a key which is not valid
+/// UTF-8 does not change the order of the others.
 #[inline]
 pub fn sort_slice_by_path_key<A, T: ?Sized + AsRef<Path>, F: FnMut(&A) -> &T>(
     slice: &mut [A],
@@ -386,9 +411,8 @@ pub fn sort_slice_by_path_key<A, T: ?Sized + AsRef<Path>, F: FnMut(&A) -> &T>(
 
 /// Reversely sort a slice by a `Path` key, but may not preserve the order of equal elements.
 ///
-/// The alphanumeric algorithm is used only if every key can be converted to UTF-8
-/// through its `OsStr` representation. If any key cannot be converted, the whole
-/// slice is sorted by native `OsStr` key ordering.
+/// The keys are compared in the same way as `compare_path`, so a key which is not valid
+/// UTF-8 does not change the order of the others.
 #[inline]
 pub fn sort_slice_rev_unstable_by_path_key<A, T: ?Sized + AsRef<Path>, F: FnMut(&A) -> &T>(
     slice: &mut [A],
@@ -404,9 +428,8 @@ pub fn sort_slice_rev_unstable_by_path_key<A, T: ?Sized + AsRef<Path>, F: FnMut(
 
 /// Reversely sort a slice by a `Path` key.
 ///
-/// The alphanumeric algorithm is used only if every key can be converted to UTF-8
-/// through its `OsStr` representation. If any key cannot be converted, the whole
-/// slice is sorted by native `OsStr` key ordering.
+/// The keys are compared in the same way as `compare_path`, so a key which is not valid
+/// UTF-8 does not change the order of the others.
 #[inline]
 pub fn sort_slice_rev_by_path_key<A, T: ?Sized + AsRef<Path>, F: FnMut(&A) -> &T>(
     slice: &mut [A],
@@ -491,13 +514,384 @@ fn sort_slice_rev_by_path_key_fallback<A, T: ?Sized + AsRef<Path>, F: FnMut(&A)
     });
 }
 
+/// Sort a slice by a `Path` key component by component, but may not preserve the order
+/// of equal elements.
+///
+/// See `compare_path_components` for how the keys are compared.
+#[inline]
+pub fn sort_slice_unstable_by_path_components_key<
+    A,
+    T: ?Sized + AsRef<Path>,
+    F: FnMut(&A) -> &T,
+>(
+    slice: &mut [A],
+    mut f: F,
+) {
+    slice.sort_unstable_by(|a, b| compare_path_components(f(a), f(b)));
+}
+
+/// Sort a slice by a `Path` key component by component.
+///
+/// See `compare_path_components` for how the keys are compared.
+#[inline]
+pub fn sort_slice_by_path_components_key<A, T: ?Sized + AsRef<Path>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    mut f: F,
+) {
+    slice.sort_by(|a, b| compare_path_components(f(a), f(b)));
+}
+
+/// Reversely sort a slice by a `Path` key component by component, but may not preserve
+/// the order of equal elements.
+///
+/// See `compare_path_components` for how the keys are compared.
+#[inline]
+pub fn sort_slice_rev_unstable_by_path_components_key<
+    A,
+    T: ?Sized + AsRef<Path>,
+    F: FnMut(&A) -> &T,
+>(
+    slice: &mut [A],
+    mut f: F,
+) {
+    slice.sort_unstable_by(|a, b| compare_path_components(f(b), f(a)));
+}
+
+/// Reversely sort a slice by a `Path` key component by component.
+///
+/// See `compare_path_components` for how the keys are compared.
+#[inline]
+pub fn sort_slice_rev_by_path_components_key<A, T: ?Sized + AsRef<Path>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    mut f: F,
+) {
+    slice.sort_by(|a, b| compare_path_components(f(b), f(a)));
+}
+
+/// Tell whether a path is a directory, for the `*_dirs_first` and `*_files_first` functions.
+///
+/// It is implemented for `bool`, so a caller predicate can be used, and for
+/// `std::fs::FileType` and `std::fs::Metadata`, so the file type information which
+/// has already been read (e.g. from `DirEntry::file_type`) can be reused.
+pub trait IsDirectory {
+    /// Return `true` if the path is a directory.
+    fn is_directory(&self) -> bool;
+}
+
+impl IsDirectory for bool {
+    #[inline]
+    fn is_directory(&self) -> bool {
+        *self
+    }
+}
+
+impl IsDirectory for FileType {
+    #[inline]
+    fn is_directory(&self) -> bool {
+        self.is_dir()
+    }
+}
+
+impl IsDirectory for Metadata {
+    #[inline]
+    fn is_directory(&self) -> bool {
+        self.is_dir()
+    }
+}
+
+impl<T: ?Sized + IsDirectory> IsDirectory for &T {
+    #[inline]
+    fn is_directory(&self) -> bool {
+        (**self).is_directory()
+    }
+}
+
+/// Sort a slice by a `Path` key, putting the elements whose keys are directories first.
+///
+/// Inside each group, the keys are compared in the same way as `compare_path`, so a key
+/// which is not valid UTF-8 does not change the order of the others.
+#[inline]
+pub fn sort_slice_by_path_key_dirs_first<
+    A,
+    T: ?Sized + AsRef<Path>,
+    F: FnMut(&A) -> &T,
+    D: IsDirectory,
+    G: FnMut(&A) -> D,
+>(
+    slice: &mut [A],
+    f: F,
+    is_dir: G,
+) {
+    sort_slice_by_path_key_grouped_inner(
+        slice,
+        f,
+        is_dir,
+        true,
+        ref_index_str_pairs_to_ref_indexes,
+        ref_index_os_str_pairs_to_ref_indexes_fallback,
+    )
+}
+
+/// Sort a slice by a `Path` key, putting the elements whose keys are not directories first.
+///
+/// Inside each group, the keys are compared in the same way as `compare_path`, so a key
+/// which is not valid UTF-8 does not change the order of the others.
+#[inline]
+pub fn sort_slice_by_path_key_files_first<
+    A,
+    T: ?Sized + AsRef<Path>,
+    F: FnMut(&A) -> &T,
+    D: IsDirectory,
+    G: FnMut(&A) -> D,
+>(
+    slice: &mut [A],
+    f: F,
+    is_dir: G,
+) {
+    sort_slice_by_path_key_grouped_inner(
+        slice,
+        f,
+        is_dir,
+        false,
+        ref_index_str_pairs_to_ref_indexes,
+        ref_index_os_str_pairs_to_ref_indexes_fallback,
+    )
+}
+
+fn sort_slice_by_path_key_grouped_inner<
+    A,
+    T: ?Sized + AsRef<Path>,
+    F: FnMut(&A) -> &T,
+    D: IsDirectory,
+    G: FnMut(&A) -> D,
+>(
+    slice: &mut [A],
+    mut f: F,
+    mut is_dir: G,
+    dirs_first: bool,
+    ref_index_str_pairs_to_ref_indexes: impl Fn(Vec<(usize, &str)>) -> Vec<usize>,
+    fallback: impl Fn(Vec<(usize, &OsStr)>) -> Vec<usize>,
+) {
+    let mut use_str = true;
+
+    // `is_dir` may make a system call, so it is called only once for each element
+    let mut front_ref_index_os_str_pairs = Vec::new();
+    let mut back_ref_index_os_str_pairs = Vec::new();
+
+    for (i, p) in slice.iter().enumerate() {
+        let s = f(p).as_ref().as_os_str();
+
+        use_str = use_str && s.to_str().is_some();
+
+        if is_dir(p).is_directory() == dirs_first {
+            front_ref_index_os_str_pairs.push((i, s));
+        } else {
+            back_ref_index_os_str_pairs.push((i, s));
+        }
+    }
+
+    let (mut ref_indexes, back_ref_indexes) = if use_str {
+        // every key is valid UTF-8 here
+        let to_ref_index_str_pairs = |pairs: Vec<(usize, _)>| {
+            pairs
+                .into_iter()
+                .filter_map(|(i, s): (usize, &OsStr)| s.to_str().map(|s| (i, s)))
+                .collect()
+        };
+
+        (
+            ref_index_str_pairs_to_ref_indexes(to_ref_index_str_pairs(
+                front_ref_index_os_str_pairs,
+            )),
+            ref_index_str_pairs_to_ref_indexes(to_ref_index_str_pairs(back_ref_index_os_str_pairs)),
+        )
+    } else {
+        (fallback(front_ref_index_os_str_pairs), fallback(back_ref_index_os_str_pairs))
+    };
+
+    ref_indexes.extend(back_ref_indexes);
+
+    sort_slice_ref_indexes(slice, ref_indexes);
+}
+
+#[inline]
+fn ref_index_os_str_pairs_to_ref_indexes_unstable_fallback(
+    mut ref_index_os_str_pairs: Vec<(usize, &OsStr)>,
+) -> Vec<usize> {
+    ref_index_os_str_pairs.sort_unstable_by(|a, b| compare_os_str_fallback(a.1, b.1));
+
+    ref_index_os_str_pairs.into_iter().map(|(i, _)| i).collect()
+}
+
+#[inline]
+fn ref_index_os_str_pairs_to_ref_indexes_fallback(
+    mut ref_index_os_str_pairs: Vec<(usize, &OsStr)>,
+) -> Vec<usize> {
+    ref_index_os_str_pairs.sort_by(|a, b| compare_os_str_fallback(a.1, b.1));
+
+    ref_index_os_str_pairs.into_iter().map(|(i, _)| i).collect()
+}
+
+// Partial std sorting
+
+/// Reorder an `OsStr` slice such that the element at `index` is at its final sorted position,
+/// like `slice::select_nth_unstable_by` with `compare_os_str`. No memory is allocated.
+///
+/// Returns the elements before `index`, the element at `index`, and the elements after
+/// `index`.
+///
+/// # Panics
+///
+/// Panics when `index >= slice.len()`.
+#[inline]
+pub fn select_nth_os_str<S: AsRef<OsStr>>(
+    slice: &mut [S],
+    index: usize,
+) -> (&mut [S], &mut S, &mut [S]) {
+    slice.select_nth_unstable_by(index, |a, b| compare_os_str(a, b))
+}
+
+/// Sort only the `k` least elements of an `OsStr` slice into `slice[..k]`. The order of the
+/// rest is unspecified. No memory is allocated.
+///
+/// The items are compared in the same way as `compare_os_str`, so an item which is not valid
+/// UTF-8 does not change the order of the others.
+#[inline]
+pub fn partial_sort_os_str_slice<S: AsRef<OsStr>>(slice: &mut [S], k: usize) {
+    partial_sort_by(slice, k, |a, b| compare_os_str(a, b));
+}
+
+/// Move the `k` greatest elements of a slice by an `OsStr` key to the front in descending
+/// order, and return them. The order of the rest is unspecified. No memory is allocated.
+///
+/// The keys are compared in the same way as `compare_os_str`, so a key which is not valid
+/// UTF-8 does not change the order of the others.
+#[inline]
+pub fn top_k_by_os_str_key<A, T: ?Sized + AsRef<OsStr>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    k: usize,
+    mut f: F,
+) -> &mut [A] {
+    let k = k.min(slice.len());
+
+    partial_sort_by(slice, k, |a, b| compare_os_str(f(b), f(a)));
+
+    &mut slice[..k]
+}
+
+/// Reorder a `Path` slice such that the element at `index` is at its final sorted position,
+/// like `slice::select_nth_unstable_by` with `compare_path`. No memory is allocated.
+///
+/// Returns the elements before `index`, the element at `index`, and the elements after
+/// `index`.
+///
+/// # Panics
+///
+/// Panics when `index >= slice.len()`.
+#[inline]
+pub fn select_nth_path<P: AsRef<Path>>(
+    slice: &mut [P],
+    index: usize,
+) -> (&mut [P], &mut P, &mut [P]) {
+    slice.select_nth_unstable_by(index, |a, b| compare_path(a, b))
+}
+
+/// Sort only the `k` least elements of a `Path` slice into `slice[..k]`. The order of the rest
+/// is unspecified. No memory is allocated.
+///
+/// The items are compared in the same way as `compare_path`, so an item which is not valid
+/// UTF-8 does not change the order of the others.
+#[inline]
+pub fn partial_sort_path_slice<P: AsRef<Path>>(slice: &mut [P], k: usize) {
+    partial_sort_by(slice, k, |a, b| compare_path(a, b));
+}
+
+/// Move the `k` greatest elements of a slice by a `Path` key to the front in descending order,
+/// and return them. The order of the rest is unspecified. No memory is allocated.
+///
+/// The keys are compared in the same way as `compare_path`, so a key which is not valid UTF-8
+/// does not change the order of the others.
+#[inline]
+pub fn top_k_by_path_key<A, T: ?Sized + AsRef<Path>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    k: usize,
+    mut f: F,
+) -> &mut [A] {
+    let k = k.min(slice.len());
+
+    partial_sort_by(slice, k, |a, b| compare_path(f(b), f(a)));
+
+    &mut slice[..k]
+}
+
+// Std binary searching
+
+/// Binary search a `Path` slice sorted by `sort_path_slice` for a path, like
+/// `slice::binary_search_by` with `compare_path`.
+///
+/// The paths are compared in the same way as `sort_path_slice` and `sort_slice_by_os_str_key`
+/// do, including the paths which are not valid UTF-8. Returns `Ok` with the index of the path
+/// if it is found, or `Err` with the index where it can be inserted to keep the slice sorted.
+#[inline]
+pub fn binary_search_path<P: AsRef<Path>, Q: ?Sized + AsRef<Path>>(
+    slice: &[P],
+    p: &Q,
+) -> Result<usize, usize> {
+    let p = p.as_ref();
+
+    slice.binary_search_by(|e| compare_path(e, p))
+}
+
+/// Binary search a slice sorted by `sort_slice_by_path_key` (or its unstable variant) for a
+/// `Path` key.
+///
+/// The keys are compared in the same way as `sort_slice_by_path_key` does, including the keys
+/// which are not valid UTF-8. Returns `Ok` with the index of an element whose key equals `key`
+/// if there is one, or `Err` with the index where an element with the key can be inserted to
+/// keep the slice sorted.
+#[inline]
+pub fn binary_search_by_path_key<
+    A,
+    K: ?Sized + AsRef<Path>,
+    T: ?Sized + AsRef<Path>,
+    F: FnMut(&A) -> &T,
+>(
+    slice: &[A],
+    key: &K,
+    mut f: F,
+) -> Result<usize, usize> {
+    let key = key.as_ref();
+
+    slice.binary_search_by(|e| compare_path(f(e), key))
+}
+
+/// Get the count of the elements of a `Path` slice sorted by `sort_path_slice` which are less
+/// than a path, which is the index of the first element not less than it.
+#[inline]
+pub fn partition_point_path<P: AsRef<Path>, Q: ?Sized + AsRef<Path>>(slice: &[P], p: &Q) -> usize {
+    let p = p.as_ref();
+
+    slice.partition_point(|e| compare_path(e, p) == Ordering::Less)
+}
+
+/// Insert a path into a `Vec` sorted by `sort_path_slice` and keep it sorted. Returns the index
+/// of the inserted path.
+#[inline]
+pub fn insert_sorted_path<P: AsRef<Path>>(vec: &mut Vec<P>, p: P) -> usize {
+    // after equal elements, like pushing to a stably sorted `Vec`
+    let index = vec.partition_point(|e| compare_path(e, &p) != Ordering::Greater);
+
+    vec.insert(index, p);
+
+    index
+}
+
 // Direct std slice sorting
 
 /// Sort an `OsStr` slice.
 ///
-/// The alphanumeric algorithm is used only if every item can be converted to UTF-8.
-/// If any item cannot be converted, the whole slice is sorted by native `OsStr`
-/// ordering.
+/// The items are compared in the same way as `compare_os_str`, so an item which is not valid
+/// UTF-8 does not change the order of the others.
 #[inline]
 pub fn sort_os_str_slice<S: AsRef<OsStr>>(slice: &mut [S]) {
     sort_slice_unstable_by_os_str_key(slice, |e| e.as_ref())
@@ -505,9 +899,8 @@ pub fn sort_os_str_slice<S: AsRef<OsStr>>(slice: &mut [S]) {
 
 /// Reversely sort an `OsStr` slice.
 ///
-/// The alphanumeric algorithm is used only if every item can be converted to UTF-8.
-/// If any item cannot be converted, the whole slice is sorted by native `OsStr`
-/// ordering.
+/// The items are compared in the same way as `compare_os_str`, so an item which is not valid
+/// UTF-8 does not change the order of the others.
 #[inline]
 pub fn sort_os_str_slice_rev<S: AsRef<OsStr>>(slice: &mut [S]) {
     sort_slice_rev_unstable_by_os_str_key(slice, |e| e.as_ref())
@@ -515,9 +908,8 @@ pub fn sort_os_str_slice_rev<S: AsRef<OsStr>>(slice: &mut [S]) {
 
 /// Sort a `CStr` slice.
 ///
-/// The alphanumeric algorithm is used only if every item can be converted to UTF-8.
-/// If any item cannot be converted, the whole slice is sorted by native `CStr`
-/// ordering.
+/// The items are compared in the same way as `compare_c_str`, so an item which is not valid
+/// UTF-8 does not change the order of the others.
 #[inline]
 pub fn sort_c_str_slice<S: AsRef<CStr>>(slice: &mut [S]) {
     sort_slice_unstable_by_c_str_key(slice, |e| e.as_ref())
@@ -525,9 +917,8 @@ pub fn sort_c_str_slice<S: AsRef<CStr>>(slice: &mut [S]) {
 
 /// Reversely sort a `CStr` slice.
 ///
-/// The alphanumeric algorithm is used only if every item can be converted to UTF-8.
-/// If any item cannot be converted, the whole slice is sorted by native `CStr`
-/// ordering.
+/// The items are compared in the same way as `compare_c_str`, so an item which is not valid
+/// UTF-8 does not change the order of the others.
 #[inline]
 pub fn sort_c_str_slice_rev<S: AsRef<CStr>>(slice: &mut [S]) {
     sort_slice_rev_unstable_by_c_str_key(slice, |e| e.as_ref())
@@ -535,9 +926,8 @@ pub fn sort_c_str_slice_rev<S: AsRef<CStr>>(slice: &mut [S]) {
 
 /// Sort a `Path` slice.
 ///
-/// The alphanumeric algorithm is used only if every item can be converted to UTF-8
-/// through its `OsStr` representation. If any item cannot be converted, the whole
-/// slice is sorted by native `OsStr` ordering.
+/// The items are compared in the same way as `compare_path`, so an item which is not valid
+/// UTF-8 does not change the order of the others.
 #[inline]
 pub fn sort_path_slice<P: AsRef<Path>>(slice: &mut [P]) {
     sort_slice_unstable_by_path_key(slice, |e| e.as_ref())
@@ -545,70 +935,143 @@ pub fn sort_path_slice<P: AsRef<Path>>(slice: &mut [P]) {
 
 /// Reversely sort a `Path` slice.
 ///
-/// The alphanumeric algorithm is used only if every item can be converted to UTF-8
-/// through its `OsStr` representation. If any item cannot be converted, the whole
-/// slice is sorted by native `OsStr` ordering.
+/// The items are compared in the same way as `compare_path`, so an item which is not valid
+/// UTF-8 does not change the order of the others.
 #[inline]
 pub fn sort_path_slice_rev<P: AsRef<Path>>(slice: &mut [P]) {
     sort_slice_rev_unstable_by_path_key(slice, |e| e.as_ref())
 }
 
-// Permutation helpers
-
+/// Sort a `Path` slice case-insensitively.
+///
+/// The items are compared in the same way as `compare_str_case_insensitive`. If an item is not
+/// valid UTF-8, all the items are compared case-insensitively by their lossy UTF-8 conversions
+/// instead, and the items whose conversions are equal are ordered in the same way as
+/// `compare_path`.
 #[inline]
-fn ref_index_str_pairs_to_ref_indexes_unstable(
-    mut ref_index_str_pairs: Vec<(usize, &str)>,
-) -> Vec<usize> {
-    ref_index_str_pairs.sort_unstable_by(|a, b| compare_str(a.1, b.1));
-
-    ref_index_str_pairs_to_ref_indexes_inner(ref_index_str_pairs)
+pub fn sort_path_slice_case_insensitive<P: AsRef<Path>>(slice: &mut [P]) {
+    sort_slice_by_path_key_inner(
+        slice,
+        |e| e.as_ref(),
+        ref_index_str_pairs_to_ref_indexes_case_insensitive_unstable,
+        sort_slice_unstable_by_path_key_case_insensitive_fallback,
+    )
 }
 
 #[inline]
-fn ref_index_str_pairs_to_ref_indexes(mut ref_index_str_pairs: Vec<(usize, &str)>) -> Vec<usize> {
-    ref_index_str_pairs.sort_by(|a, b| compare_str(a.1, b.1));
+fn sort_slice_unstable_by_path_key_case_insensitive_fallback<
+    A,
+    T: ?Sized + AsRef<Path>,
+    F: FnMut(&A) -> &T,
+>(
+    slice: &mut [A],
+    mut f: F,
+) {
+    let mut ref_index_key_triples: Vec<(usize, Cow<str>, &OsStr)> = slice
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let p = f(p).as_ref().as_os_str();
+
+            (i, p.to_string_lossy(), p)
+        })
+        .collect();
+
+    ref_index_key_triples.sort_unstable_by(|a, b| {
+        compare_str_case_insensitive(&a.1, &b.1).then_with(|| compare_os_str_fallback(a.2, b.2))
+    });
 
-    ref_index_str_pairs_to_ref_indexes_inner(ref_index_str_pairs)
+    let ref_indexes = ref_index_key_triples.into_iter().map(|(i, ..)| i).collect();
+
+    sort_slice_ref_indexes(slice, ref_indexes);
 }
 
+/// Sort a `Path` slice component by component.
+///
+/// See `compare_path_components` for how the items are compared.
 #[inline]
-fn ref_index_str_pairs_to_ref_indexes_rev_unstable(
-    mut ref_index_str_pairs: Vec<(usize, &str)>,
-) -> Vec<usize> {
-    ref_index_str_pairs.sort_unstable_by(|a, b| compare_str(b.1, a.1));
-
-    ref_index_str_pairs_to_ref_indexes_inner(ref_index_str_pairs)
+pub fn sort_path_slice_by_components<P: AsRef<Path>>(slice: &mut [P]) {
+    sort_slice_unstable_by_path_components_key(slice, |e| e.as_ref())
 }
 
+/// Reversely sort a `Path` slice component by component.
+///
+/// See `compare_path_components` for how the items are compared.
 #[inline]
-fn ref_index_str_pairs_to_ref_indexes_rev(
-    mut ref_index_str_pairs: Vec<(usize, &str)>,
-) -> Vec<usize> {
-    ref_index_str_pairs.sort_by(|a, b| compare_str(b.1, a.1));
-
-    ref_index_str_pairs_to_ref_indexes_inner(ref_index_str_pairs)
+pub fn sort_path_slice_rev_by_components<P: AsRef<Path>>(slice: &mut [P]) {
+    sort_slice_rev_unstable_by_path_components_key(slice, |e| e.as_ref())
 }
 
+/// Sort a `Path` slice, putting the directories first.
+///
+/// Inside each group, the items are compared in the same way as `compare_path`, so an item
+/// which is not valid UTF-8 does not change the order of the others.
+///
+/// ```rust
+/// use std::path::Path;
+///
+/// let mut paths = [
+///     Path::new("b.txt"),
+///     Path::new("src"),
+///     Path::new("a.txt"),
+///     Path::new("docs"),
+/// ];
+///
+/// alphanumeric_sort::sort_path_slice_dirs_first(&mut paths, |p| {
+///     p.extension().is_none()
+/// });
+///
+/// assert_eq!(
+///     [
+///         Path::new("docs"),
+///         Path::new("src"),
+///         Path::new("a.txt"),
+///         Path::new("b.txt")
+///     ],
+///     paths
+/// );
+/// ```
 #[inline]
-fn ref_index_str_pairs_to_ref_indexes_inner(ref_index_str_pairs: Vec<(usize, &str)>) -> Vec<usize> {
-    ref_index_str_pairs.into_iter().map(|(i, _)| i).collect()
+pub fn sort_path_slice_dirs_first<P: AsRef<Path>, D: IsDirectory, G: FnMut(&P) -> D>(
+    slice: &mut [P],
+    is_dir: G,
+) {
+    sort_slice_by_path_key_grouped_inner(
+        slice,
+        |e| e.as_ref(),
+        is_dir,
+        true,
+        ref_index_str_pairs_to_ref_indexes_unstable,
+        ref_index_os_str_pairs_to_ref_indexes_unstable_fallback,
+    )
 }
 
+/// Sort a `Path` slice, putting the files (everything which is not a directory) first.
+///
+/// Inside each group, the items are compared in the same way as `compare_path`, so an item
+/// which is not valid UTF-8 does not change the order of the others.
 #[inline]
-fn sort_slice_ref_indexes<S>(slice: &mut [S], mut permutation: Vec<usize>) {
-    // Cycle Decomposition
-    for i in 0..permutation.len() {
-        let mut current = i;
-
-        while permutation[current] != i {
-            let next = permutation[current];
+pub fn sort_path_slice_files_first<P: AsRef<Path>, D: IsDirectory, G: FnMut(&P) -> D>(
+    slice: &mut [P],
+    is_dir: G,
+) {
+    sort_slice_by_path_key_grouped_inner(
+        slice,
+        |e| e.as_ref(),
+        is_dir,
+        false,
+        ref_index_str_pairs_to_ref_indexes_unstable,
+        ref_index_os_str_pairs_to_ref_indexes_unstable_fallback,
+    )
+}
 
-            slice.swap(current, next);
+// Permutation helpers
 
-            permutation[current] = current;
-            current = next;
-        }
+#[inline]
+fn ref_index_str_pairs_to_ref_indexes_case_insensitive_unstable(
+    mut ref_index_str_pairs: Vec<(usize, &str)>,
+) -> Vec<usize> {
+    ref_index_str_pairs.sort_unstable_by(|a, b| compare_str_case_insensitive(a.1, b.1));
 
-        permutation[current] = current;
-    }
+    ref_index_str_pairs_to_ref_indexes_inner(ref_index_str_pairs)
 }
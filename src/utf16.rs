@@ -0,0 +1,84 @@
+use core::{char::decode_utf16, cmp::Ordering};
+
+use crate::compare_units;
+
+/// Compare two UTF-16 strings.
+///
+/// Surrogate pairs are decoded on the fly, so valid UTF-16 strings are compared in the same way as `compare_str` compares their UTF-8 counterparts. An unpaired surrogate is compared as its code point (U+D800 to U+DFFF), like `compare_os_str` does for a surrogate in the WTF-8 representation on Windows. No memory is allocated.
+///
+/// ```rust
+/// use core::cmp::Ordering;
+///
+/// let a: Vec<u16> = "shot-2".encode_utf16().collect();
+/// let b: Vec<u16> = "shot-10".encode_utf16().collect();
+///
+/// assert_eq!(Ordering::Less, alphanumeric_sort::compare_utf16(&a, &b));
+/// ```
+#[inline]
+pub fn compare_utf16<A: AsRef<[u16]>, B: AsRef<[u16]>>(a: A, b: B) -> Ordering {
+    compare_units(utf16_units(a.as_ref()), utf16_units(b.as_ref()))
+}
+
+#[inline]
+fn utf16_units(s: &[u16]) -> impl Iterator<Item = u32> + '_ {
+    decode_utf16(s.iter().copied()).map(|r| match r {
+        Ok(c) => c as u32,
+        Err(e) => e.unpaired_surrogate() as u32,
+    })
+}
+
+// UTF-16-key sorting
+
+/// Sort a slice by a `[u16]` key, but may not preserve the order of equal elements. No memory is allocated.
+#[inline]
+pub fn sort_slice_unstable_by_utf16_key<A, T: ?Sized + AsRef<[u16]>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    mut f: F,
+) {
+    slice.sort_unstable_by(|a, b| compare_utf16(f(a), f(b)));
+}
+
+/// Sort a slice by a `[u16]` key.
+///
+/// Unlike the other UTF-16 functions, this allocates a buffer of about half the length of the slice, as `slice::sort_by` does, to keep the order of equal elements. `sort_slice_unstable_by_utf16_key` sorts without allocating.
+#[inline]
+pub fn sort_slice_by_utf16_key<A, T: ?Sized + AsRef<[u16]>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    mut f: F,
+) {
+    slice.sort_by(|a, b| compare_utf16(f(a), f(b)));
+}
+
+/// Reversely sort a slice by a `[u16]` key, but may not preserve the order of equal elements. No memory is allocated.
+#[inline]
+pub fn sort_slice_rev_unstable_by_utf16_key<A, T: ?Sized + AsRef<[u16]>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    mut f: F,
+) {
+    slice.sort_unstable_by(|a, b| compare_utf16(f(b), f(a)));
+}
+
+/// Reversely sort a slice by a `[u16]` key.
+///
+/// Unlike the other UTF-16 functions, this allocates a buffer of about half the length of the slice, as `slice::sort_by` does, to keep the order of equal elements. `sort_slice_rev_unstable_by_utf16_key` sorts without allocating.
+#[inline]
+pub fn sort_slice_rev_by_utf16_key<A, T: ?Sized + AsRef<[u16]>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    mut f: F,
+) {
+    slice.sort_by(|a, b| compare_utf16(f(b), f(a)));
+}
+
+// Direct UTF-16 slice sorting
+
+/// Sort a `[u16]` slice. No memory is allocated.
+#[inline]
+pub fn sort_utf16_slice<S: AsRef<[u16]>>(slice: &mut [S]) {
+    slice.sort_unstable_by(|a, b| compare_utf16(a, b));
+}
+
+/// Reversely sort a `[u16]` slice. No memory is allocated.
+#[inline]
+pub fn sort_utf16_slice_rev<S: AsRef<[u16]>>(slice: &mut [S]) {
+    slice.sort_unstable_by(|a, b| compare_utf16(b, a));
+}
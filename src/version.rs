@@ -0,0 +1,458 @@
+use core::cmp::Ordering;
+
+use crate::compare_str;
+
+/// Compare two version strings.
+///
+/// The [Semantic Versioning 2.0.0](https://semver.org/spec/v2.0.0.html) precedence rules are followed where they apply.
+///
+/// * The text before the first ASCII digit (e.g. `"v"`, `"app-"`) is compared first, by Unicode scalar values.
+/// * The dot-separated numbers of the version core (e.g. `"1.2.10"`) are compared numerically one by one. A missing number is zero, so `"1.2"` has the same precedence as `"1.2.0"`.
+/// * A version with a pre-release part (e.g. `"-rc.1"`) has lower precedence than the one without. Pre-release identifiers are compared one by one. Numeric identifiers are compared numerically, and they have lower precedence than alphanumeric identifiers, which are compared in ASCII order. A shorter set of identifiers has lower precedence if all the preceding identifiers are equal.
+/// * Build metadata (e.g. `"+build.5"`) is ignored.
+/// * Anything else after the version (e.g. `".tar.gz"`, `"_x64"`) is compared with the alphanumeric algorithm.
+///
+/// Versions which have the same precedence are equal, like `"1.0.0+a"` and `"1.0.0+b"`.
+///
+/// ```rust
+/// use core::cmp::Ordering;
+///
+/// assert_eq!(
+///     Ordering::Less,
+///     alphanumeric_sort::compare_version("1.0.0-rc.1", "1.0.0")
+/// );
+/// assert_eq!(
+///     Ordering::Greater,
+///     alphanumeric_sort::compare_str("1.0.0-rc.1", "1.0.0")
+/// );
+/// ```
+pub fn compare_version<A: AsRef<str>, B: AsRef<str>>(a: A, b: B) -> Ordering {
+    let va = Version::parse(a.as_ref());
+    let vb = Version::parse(b.as_ref());
+
+    va.prefix
+        .cmp(vb.prefix)
+        .then_with(|| cmp_core(va.core, vb.core))
+        .then_with(|| match (va.pre_release, vb.pre_release) {
+            (Some(pa), Some(pb)) => cmp_pre_release(pa, pb),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        })
+        .then_with(|| compare_str(va.tail, vb.tail))
+}
+
+struct Version<'a> {
+    /// The text before the first ASCII digit.
+    prefix:      &'a str,
+    /// The dot-separated numbers, which is empty if there is no digit.
+    core:        &'a str,
+    /// The pre-release identifiers without the leading `'-'`.
+    pre_release: Option<&'a str>,
+    /// The text after the build metadata.
+    tail:        &'a str,
+}
+
+impl<'a> Version<'a> {
+    fn parse(s: &'a str) -> Self {
+        let start = s.bytes().position(|b| b.is_ascii_digit()).unwrap_or(s.len());
+
+        let prefix = &s[..start];
+        let rest = &s[start..];
+
+        // digits ('.' digits)*
+        let bytes = rest.as_bytes();
+        let mut end = 0;
+
+        while end < bytes.len() && bytes[end].is_ascii_digit() {
+            end += 1;
+
+            if end + 1 < bytes.len() && bytes[end] == b'.' && bytes[end + 1].is_ascii_digit() {
+                end += 1;
+            }
+        }
+
+        let core = &rest[..end];
+        let rest = &rest[end..];
+
+        let (pre_release, rest) = match rest.strip_prefix('-') {
+            Some(r) => match identifiers_len(r) {
+                0 => (None, rest),
+                n => (Some(&r[..n]), &r[n..]),
+            },
+            None => (None, rest),
+        };
+
+        let tail = match rest.strip_prefix('+') {
+            Some(r) => match identifiers_len(r) {
+                0 => rest,
+                n => &r[n..],
+            },
+            None => rest,
+        };
+
+        Version {
+            prefix,
+            core,
+            pre_release,
+            tail,
+        }
+    }
+}
+
+/// Get the length of the dot-separated identifiers made up of ASCII alphanumerics and hyphens at the start of `s`.
+fn identifiers_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut end = 0;
+
+    loop {
+        let len =
+            bytes[end..].iter().take_while(|b| b.is_ascii_alphanumeric() || **b == b'-').count();
+
+        if len == 0 {
+            // an empty identifier is not allowed, so a trailing dot is not a part of them
+            return end.saturating_sub(1);
+        }
+
+        end += len;
+
+        if bytes.get(end) == Some(&b'.') {
+            end += 1;
+        } else {
+            return end;
+        }
+    }
+}
+
+/// Compare two runs of ASCII digits numerically.
+#[inline]
+fn cmp_numeric(a: &[u8], b: &[u8]) -> Ordering {
+    let a = &a[a.iter().take_while(|c| **c == b'0').count()..];
+    let b = &b[b.iter().take_while(|c| **c == b'0').count()..];
+
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+fn cmp_core(a: &str, b: &str) -> Ordering {
+    match (a.is_empty(), b.is_empty()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Less,
+        (false, true) => return Ordering::Greater,
+        (false, false) => (),
+    }
+
+    let mut na = a.split('.');
+    let mut nb = b.split('.');
+
+    loop {
+        match (na.next(), nb.next()) {
+            (None, None) => return Ordering::Equal,
+            (da, db) => {
+                match cmp_numeric(da.unwrap_or("0").as_bytes(), db.unwrap_or("0").as_bytes()) {
+                    Ordering::Equal => (),
+                    ordering => return ordering,
+                }
+            },
+        }
+    }
+}
+
+fn cmp_pre_release(a: &str, b: &str) -> Ordering {
+    let mut ia = a.split('.');
+    let mut ib = b.split('.');
+
+    loop {
+        let (xa, xb) = match (ia.next(), ib.next()) {
+            (Some(xa), Some(xb)) => (xa, xb),
+            (Some(_), None) => return Ordering::Greater,
+            (None, Some(_)) => return Ordering::Less,
+            (None, None) => return Ordering::Equal,
+        };
+
+        let ordering = match (
+            xa.bytes().all(|b| b.is_ascii_digit()),
+            xb.bytes().all(|b| b.is_ascii_digit()),
+        ) {
+            (true, true) => cmp_numeric(xa.as_bytes(), xb.as_bytes()),
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => xa.cmp(xb),
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+/// Compare two Debian package versions in the same way as `dpkg --compare-versions`.
+///
+/// A version is made up of `[epoch:]upstream_version[-debian_revision]`. The epochs are compared numerically, then the upstream versions and the revisions are compared part by part. Non-digit parts are compared character by character, where letters sort earlier than other characters and `'~'` sorts earlier than anything, even the end of a part. Digit parts are compared numerically. A missing epoch is `0` and a missing revision is the same as `0`.
+///
+/// ```rust
+/// use core::cmp::Ordering;
+///
+/// assert_eq!(
+///     Ordering::Less,
+///     alphanumeric_sort::compare_debian_version("1.0~rc1-1", "1.0-1")
+/// );
+/// assert_eq!(
+///     Ordering::Greater,
+///     alphanumeric_sort::compare_debian_version("1:0.9", "2.0")
+/// );
+/// ```
+pub fn compare_debian_version<A: AsRef<str>, B: AsRef<str>>(a: A, b: B) -> Ordering {
+    let (ea, ua, ra) = split_debian_version(a.as_ref());
+    let (eb, ub, rb) = split_debian_version(b.as_ref());
+
+    dpkg_verrevcmp(ea, eb).then_with(|| dpkg_verrevcmp(ua, ub)).then_with(|| dpkg_verrevcmp(ra, rb))
+}
+
+/// Split a Debian package version into its epoch, upstream version and revision.
+fn split_debian_version(s: &str) -> (&str, &str, &str) {
+    let s = s.trim_matches(|c: char| c.is_ascii_whitespace());
+
+    let (epoch, rest) = match s.find(':') {
+        Some(i) => (&s[..i], &s[i + 1..]),
+        None => ("", s),
+    };
+
+    match rest.rfind('-') {
+        Some(i) => (epoch, &rest[..i], &rest[i + 1..]),
+        None => (epoch, rest, ""),
+    }
+}
+
+#[inline]
+fn dpkg_order(c: Option<&u8>) -> i32 {
+    match c {
+        None => 0,
+        Some(c) if c.is_ascii_digit() => 0,
+        Some(c) if c.is_ascii_alphabetic() => *c as i32,
+        Some(b'~') => -1,
+        Some(c) => *c as i32 + 256,
+    }
+}
+
+fn dpkg_verrevcmp(a: &str, b: &str) -> Ordering {
+    // dpkg orders non-digit characters in its own way and does not tell apart leading zeros, so only `cmp_numeric` is shared with the other version comparisons
+    let a = a.as_bytes();
+    let b = b.as_bytes();
+
+    let is_digit = |c: &u8| c.is_ascii_digit();
+
+    let mut i = 0;
+    let mut j = 0;
+
+    while i < a.len() || j < b.len() {
+        while a.get(i).is_some_and(|c| !is_digit(c)) || b.get(j).is_some_and(|c| !is_digit(c)) {
+            match dpkg_order(a.get(i)).cmp(&dpkg_order(b.get(j))) {
+                Ordering::Equal => (),
+                ordering => return ordering,
+            }
+
+            i += 1;
+            j += 1;
+        }
+
+        let sa = i;
+        let sb = j;
+
+        while a.get(i).is_some_and(is_digit) {
+            i += 1;
+        }
+
+        while b.get(j).is_some_and(is_digit) {
+            j += 1;
+        }
+
+        match cmp_numeric(&a[sa..i], &b[sb..j]) {
+            Ordering::Equal => (),
+            ordering => return ordering,
+        }
+    }
+
+    Ordering::Equal
+}
+
+/// Compare two RPM package versions in the same way as `rpmdev-vercmp`.
+///
+/// A version is made up of `[epoch:]version[-release]`. The epochs, the versions and the releases are compared in turn with the `rpmvercmp` algorithm. Alphabetic and numeric segments are compared one by one, and any other characters only separate them. Numeric segments are compared numerically and are newer than alphabetic segments. `'~'` sorts earlier than anything, even the end of a version, and `'^'` sorts earlier than anything except the end of a version. A missing epoch is `0`.
+///
+/// As in RPM, the releases are only compared when both versions have one, so `"1.0"` is equal to both `"1.0-1"` and `"1.0-2"`. Mixing versions with and without releases therefore does not give a total order, and such versions must not be sorted with this function (e.g. by `slice::sort_by`), which may then produce an unspecified order or panic.
+///
+/// ```rust
+/// use core::cmp::Ordering;
+///
+/// assert_eq!(
+///     Ordering::Less,
+///     alphanumeric_sort::compare_rpm_version("1.0~rc1-1", "1.0-1")
+/// );
+/// assert_eq!(
+///     Ordering::Greater,
+///     alphanumeric_sort::compare_rpm_version("1.0^git1-1", "1.0-1")
+/// );
+/// ```
+pub fn compare_rpm_version<A: AsRef<str>, B: AsRef<str>>(a: A, b: B) -> Ordering {
+    let (ea, va, ra) = split_rpm_version(a.as_ref());
+    let (eb, vb, rb) = split_rpm_version(b.as_ref());
+
+    rpmvercmp(ea, eb).then_with(|| rpmvercmp(va, vb)).then_with(|| match (ra, rb) {
+        (Some(ra), Some(rb)) => rpmvercmp(ra, rb),
+        _ => Ordering::Equal,
+    })
+}
+
+/// Split an RPM package version into its epoch, version and release.
+fn split_rpm_version(s: &str) -> (&str, &str, Option<&str>) {
+    let digits = s.bytes().take_while(|c| c.is_ascii_digit()).count();
+
+    let (epoch, rest) = if s.as_bytes().get(digits) == Some(&b':') {
+        (if digits == 0 { "0" } else { &s[..digits] }, &s[digits + 1..])
+    } else {
+        ("0", s)
+    };
+
+    match rest.rfind('-') {
+        Some(i) => (epoch, &rest[..i], Some(&rest[i + 1..])),
+        None => (epoch, rest, None),
+    }
+}
+
+fn rpmvercmp(a: &str, b: &str) -> Ordering {
+    // rpm skips separators and compares alphabetic segments as a whole, so only `cmp_numeric` is shared with the other version comparisons
+    if a == b {
+        return Ordering::Equal;
+    }
+
+    let a = a.as_bytes();
+    let b = b.as_bytes();
+
+    let is_separator = |c: &u8| !c.is_ascii_alphanumeric() && *c != b'~' && *c != b'^';
+
+    let mut i = 0;
+    let mut j = 0;
+
+    while i < a.len() || j < b.len() {
+        while a.get(i).is_some_and(is_separator) {
+            i += 1;
+        }
+
+        while b.get(j).is_some_and(is_separator) {
+            j += 1;
+        }
+
+        let ca = a.get(i);
+        let cb = b.get(j);
+
+        // '~' sorts before everything else
+        if ca == Some(&b'~') || cb == Some(&b'~') {
+            if ca != Some(&b'~') {
+                return Ordering::Greater;
+            }
+
+            if cb != Some(&b'~') {
+                return Ordering::Less;
+            }
+
+            i += 1;
+            j += 1;
+
+            continue;
+        }
+
+        // '^' sorts after the end, but before everything else
+        if ca == Some(&b'^') || cb == Some(&b'^') {
+            if ca.is_none() {
+                return Ordering::Less;
+            }
+
+            if cb.is_none() {
+                return Ordering::Greater;
+            }
+
+            if ca != Some(&b'^') {
+                return Ordering::Greater;
+            }
+
+            if cb != Some(&b'^') {
+                return Ordering::Less;
+            }
+
+            i += 1;
+            j += 1;
+
+            continue;
+        }
+
+        let is_numeric = match ca {
+            Some(c) if cb.is_some() => c.is_ascii_digit(),
+            _ => break,
+        };
+
+        let is_segment =
+            |c: &u8| if is_numeric { c.is_ascii_digit() } else { c.is_ascii_alphabetic() };
+
+        let sa = i;
+        let sb = j;
+
+        while a.get(i).is_some_and(is_segment) {
+            i += 1;
+        }
+
+        while b.get(j).is_some_and(is_segment) {
+            j += 1;
+        }
+
+        // numeric segments are newer than alphabetic segments
+        if sb == j {
+            return if is_numeric { Ordering::Greater } else { Ordering::Less };
+        }
+
+        let ordering =
+            if is_numeric { cmp_numeric(&a[sa..i], &b[sb..j]) } else { a[sa..i].cmp(&b[sb..j]) };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    // whichever version still has characters left over wins
+    match (i < a.len(), j < b.len()) {
+        (false, false) => Ordering::Equal,
+        (false, true) => Ordering::Less,
+        (true, _) => Ordering::Greater,
+    }
+}
+
+// Version sorting
+
+/// Sort a slice by a version key. Elements whose keys have the same precedence keep their original order.
+#[inline]
+pub fn sort_slice_by_version_key<A, T: ?Sized + AsRef<str>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    mut f: F,
+) {
+    slice.sort_by(|a, b| compare_version(f(a), f(b)));
+}
+
+/// Reversely sort a slice by a version key. Elements whose keys have the same precedence keep their original order.
+#[inline]
+pub fn sort_slice_rev_by_version_key<A, T: ?Sized + AsRef<str>, F: FnMut(&A) -> &T>(
+    slice: &mut [A],
+    mut f: F,
+) {
+    slice.sort_by(|a, b| compare_version(f(b), f(a)));
+}
+
+/// Sort a slice of version strings. Versions which have the same precedence keep their original order.
+#[inline]
+pub fn sort_version_slice<S: AsRef<str>>(slice: &mut [S]) {
+    slice.sort_by(|a, b| compare_version(a, b));
+}
+
+/// Reversely sort a slice of version strings. Versions which have the same precedence keep their original order.
+#[inline]
+pub fn sort_version_slice_rev<S: AsRef<str>>(slice: &mut [S]) {
+    slice.sort_by(|a, b| compare_version(b, a));
+}
@@ -511,3 +511,1550 @@ fn sort_slice_by_path_key() {
         array
     );
 }
+
+#[test]
+fn collator_default() {
+    let collator = alphanumeric_sort::Collator::new();
+
+    let pairs = [
+        ("0", "1"),
+        ("abc", "bbb"),
+        ("abc321", "abc3210"),
+        ("abc5d67", "abc321"),
+        ("1", "中"),
+        ("1個", "1.1個"),
+        ("1", "01"),
+        ("01", "1a"),
+        ("1-", "01-"),
+        ("01-", "1："),
+        ("00章", "0："),
+        ("00001", "000001"),
+        ("01a001b01", "001a01b01"),
+        ("第1章", "第1-2章"),
+        ("abcd", "abcd"),
+    ];
+
+    for (a, b) in pairs.iter() {
+        assert_eq!(alphanumeric_sort::compare_str(a, b), collator.compare(a, b));
+        assert_eq!(alphanumeric_sort::compare_str(b, a), collator.compare(b, a));
+    }
+}
+
+#[test]
+fn collator_leading_zeros() {
+    let collator = alphanumeric_sort::Collator::new().leading_zeros(false);
+
+    assert_eq!(Ordering::Equal, collator.compare("01", "1"));
+    assert_eq!(Ordering::Equal, collator.compare("01a001b01", "001a01b01"));
+    assert_eq!(Ordering::Less, collator.compare("01", "1a"));
+    assert_eq!(Ordering::Greater, collator.compare("002", "1"));
+}
+
+#[test]
+fn collator_flip_after_number() {
+    let collator = alphanumeric_sort::Collator::new().flip_after_number(false);
+
+    assert_eq!(Ordering::Greater, collator.compare("第1章", "第1-2章"));
+    assert_eq!(Ordering::Less, collator.compare("1", "中"));
+    assert_eq!(Ordering::Less, collator.compare("1-", "1："));
+}
+
+#[test]
+fn collator_case_insensitive() {
+    let collator = alphanumeric_sort::Collator::new().case_insensitive(true);
+
+    assert_eq!(Ordering::Less, collator.compare("shot-1", "Shot-2"));
+    assert_eq!(Ordering::Greater, collator.compare("Shot-2", "shot-1"));
+    assert_eq!(Ordering::Less, collator.compare("Shot-2", "shot-2"));
+    assert_eq!(Ordering::Less, collator.compare("ΣΑΣ", "σας"));
+    assert_eq!(Ordering::Greater, collator.compare("straße", "STRAẞE"));
+    assert_eq!(Ordering::Equal, collator.compare("Shot-2", "Shot-2"));
+
+    // the first difference decides the order
+    assert_eq!(Ordering::Less, collator.compare("A01", "a1"));
+    assert_eq!(Ordering::Greater, collator.compare("a01", "A1"));
+}
+
+#[test]
+fn compare_case_insensitive() {
+    assert_eq!(Ordering::Less, alphanumeric_sort::compare_str_case_insensitive("shot-1", "Shot-2"));
+    assert_eq!(
+        Ordering::Greater,
+        alphanumeric_sort::compare_str_case_insensitive("Shot-2", "shot-1")
+    );
+    assert_eq!(Ordering::Less, alphanumeric_sort::compare_str_case_insensitive("Shot-2", "shot-2"));
+    assert_eq!(
+        Ordering::Equal,
+        alphanumeric_sort::compare_str_case_insensitive("shot-2", "shot-2")
+    );
+}
+
+#[test]
+fn sort_str_slice_case_insensitive() {
+    let mut array = ["shot-11", "Shot-2", "shot-1", "SHOT-2", "shot-2", "Shot-10"];
+
+    alphanumeric_sort::sort_str_slice_case_insensitive(&mut array);
+
+    assert_eq!(["shot-1", "SHOT-2", "Shot-2", "shot-2", "Shot-10", "shot-11"], array);
+}
+
+#[cfg(all(feature = "std", unix))]
+#[test]
+fn sort_path_slice_case_insensitive_non_utf8() {
+    use std::{ffi::OsStr, os::unix::ffi::OsStrExt, path::Path};
+
+    let p = |s: &'static [u8]| Path::new(OsStr::from_bytes(s));
+
+    let mut array = [p(b"b"), p(b"A"), p(b"\xffz"), p(b"a"), p(b"B"), p(b"a-2\xff"), p(b"A-10")];
+
+    alphanumeric_sort::sort_path_slice_case_insensitive(&mut array);
+
+    assert_eq!([p(b"A"), p(b"a"), p(b"a-2\xff"), p(b"A-10"), p(b"B"), p(b"b"), p(b"\xffz")], array);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn sort_path_slice_case_insensitive() {
+    let mut array = [
+        Path::new("shot-11"),
+        Path::new("Shot-2"),
+        Path::new("shot-1"),
+        Path::new("SHOT-2"),
+        Path::new("shot-2"),
+        Path::new("Shot-10"),
+    ];
+
+    alphanumeric_sort::sort_path_slice_case_insensitive(&mut array);
+
+    assert_eq!(
+        [
+            Path::new("shot-1"),
+            Path::new("SHOT-2"),
+            Path::new("Shot-2"),
+            Path::new("shot-2"),
+            Path::new("Shot-10"),
+            Path::new("shot-11")
+        ],
+        array
+    );
+}
+
+#[test]
+fn collator_unicode_digits() {
+    let collator = alphanumeric_sort::Collator::new().unicode_digits(true);
+
+    assert_eq!(Ordering::Greater, collator.compare("第１０章", "第９章"));
+    assert_eq!(Ordering::Greater, collator.compare("第１０章", "第9章"));
+    assert_eq!(Ordering::Greater, collator.compare("١٠", "9"));
+    assert_eq!(Ordering::Less, collator.compare("अध्याय २", "अध्याय १०"));
+
+    // leading zeros, then scripts
+    assert_eq!(Ordering::Greater, collator.compare("０１", "1"));
+    assert_eq!(Ordering::Greater, collator.compare("１", "1"));
+    assert_eq!(Ordering::Equal, collator.leading_zeros(false).compare("０１", "1"));
+
+    // a digit sequence never mixes scripts
+    assert_eq!(Ordering::Less, collator.compare("1١", "2"));
+    assert_eq!(Ordering::Less, collator.compare("1١", "1٢"));
+
+    // disabled by default
+    assert_eq!(Ordering::Less, alphanumeric_sort::Collator::new().compare("第１０章", "第９章"));
+}
+
+#[test]
+fn collator_cjk_numerals() {
+    let collator = alphanumeric_sort::Collator::new().cjk_numerals(true);
+
+    assert_eq!(Ordering::Greater, collator.compare("第十二章", "第3章"));
+    assert_eq!(Ordering::Greater, collator.compare("第十二章", "第十一章"));
+    assert_eq!(Ordering::Greater, collator.compare("第一百零三回", "第九十九回"));
+    assert_eq!(Ordering::Less, collator.compare("第一百零三回", "第一百一十回"));
+    assert_eq!(Ordering::Greater, collator.compare("三万五千", "9999"));
+    assert_eq!(Ordering::Greater, collator.compare("二〇二三年", "2022年"));
+    assert_eq!(Ordering::Less, collator.compare("二〇二三年", "2024年"));
+
+    // leading zeros, then spellings
+    assert_eq!(Ordering::Greater, collator.compare("〇一", "1"));
+    assert_eq!(Ordering::Greater, collator.compare("第十二章", "第12章"));
+    assert_eq!(Ordering::Equal, collator.leading_zeros(false).compare("第十二章", "第12章"));
+
+    let mut array = ["第十二章", "第二章", "第一百零三章", "第十章", "第一章", "第20章"];
+
+    array.sort_by(|a, b| collator.compare(a, b));
+
+    assert_eq!(["第一章", "第二章", "第十章", "第十二章", "第20章", "第一百零三章"], array);
+}
+
+#[test]
+fn collator_roman_numerals() {
+    let collator = alphanumeric_sort::Collator::new().roman_numerals(true);
+
+    assert_eq!(Ordering::Less, collator.compare("Part II", "Part IX"));
+    assert_eq!(Ordering::Less, collator.compare("Part IX", "Part X"));
+    assert_eq!(Ordering::Greater, collator.compare("Appendix iv", "Appendix ii"));
+    assert_eq!(Ordering::Greater, collator.compare("Rocky IV.mkv", "Rocky 3.mkv"));
+    assert_eq!(Ordering::Greater, collator.compare("MMXXIV", "MCMXCIX"));
+
+    // not whole words
+    assert_eq!(Ordering::Less, collator.compare("REMIX", "REMV"));
+    assert_eq!(Ordering::Less, collator.compare("ADC", "ADI"));
+    assert_eq!(Ordering::Less, collator.compare("Part IXa", "Part Ia"));
+
+    // not canonical, so they are just letters, which are greater than numbers
+    assert_eq!(Ordering::Greater, collator.compare("Part IIII", "Part V"));
+    assert_eq!(Ordering::Greater, collator.compare("Part IIII", "Part III"));
+    assert_eq!(Ordering::Greater, collator.compare("Part Iv", "Part ii"));
+
+    let mut array = ["Part X", "Part II", "Part IX", "Part I", "Part V", "Part IV", "Part III"];
+
+    array.sort_by(|a, b| collator.compare(a, b));
+
+    assert_eq!(["Part I", "Part II", "Part III", "Part IV", "Part V", "Part IX", "Part X"], array);
+}
+
+#[test]
+fn collator_signed_numbers() {
+    let collator = alphanumeric_sort::Collator::new().signed_numbers(true);
+
+    assert_eq!(Ordering::Less, collator.compare("offset_-3.log", "offset_0.log"));
+    assert_eq!(Ordering::Less, collator.compare("offset_-3.log", "offset_-2.log"));
+    assert_eq!(Ordering::Greater, collator.compare("offset_-3.log", "offset_-10.log"));
+    assert_eq!(Ordering::Less, collator.compare("temp--5", "temp-10"));
+    assert_eq!(Ordering::Less, collator.compare("-1", "1"));
+
+    // hyphens
+    assert_eq!(Ordering::Less, collator.compare("temp-5", "temp-10"));
+    assert_eq!(Ordering::Less, collator.compare("5-3", "5-4"));
+
+    // -0 is zero
+    assert_eq!(Ordering::Less, collator.compare("-0", "0"));
+    assert_eq!(Ordering::Less, collator.compare("-0", "1"));
+    assert_eq!(Ordering::Equal, collator.leading_zeros(false).compare("-0", "0"));
+
+    let mut array = ["offset_2", "offset_-1", "offset_0", "offset_-10", "offset_10"];
+
+    array.sort_by(|a, b| collator.compare(a, b));
+
+    assert_eq!(["offset_-10", "offset_-1", "offset_0", "offset_2", "offset_10"], array);
+}
+
+#[test]
+fn collator_decimal_mode() {
+    use alphanumeric_sort::{Collator, DecimalMode};
+
+    let integer = Collator::new();
+    let fraction = Collator::new().decimal_mode(DecimalMode::Fraction);
+
+    assert_eq!(Ordering::Less, integer.compare("v1.5", "v1.10"));
+    assert_eq!(Ordering::Greater, fraction.compare("v1.5", "v1.10"));
+    assert_eq!(Ordering::Greater, integer.compare("scale_0.25", "scale_0.3"));
+    assert_eq!(Ordering::Less, fraction.compare("scale_0.25", "scale_0.3"));
+
+    assert_eq!(Ordering::Less, fraction.compare("scale_0.3", "scale_1"));
+    assert_eq!(Ordering::Greater, fraction.compare("scale_1.01", "scale_1"));
+    assert_eq!(Ordering::Greater, fraction.compare("scale_1.", "scale_1.0"));
+
+    // trailing zeros
+    assert_eq!(Ordering::Less, fraction.compare("scale_0.3", "scale_0.30"));
+    assert_eq!(Ordering::Equal, fraction.leading_zeros(false).compare("scale_0.3", "scale_0.30"));
+
+    // with signs
+    let signed = fraction.signed_numbers(true);
+
+    assert_eq!(Ordering::Less, signed.compare("offset_-0.5", "offset_0"));
+    assert_eq!(Ordering::Less, signed.compare("offset_-0.5", "offset_-0.25"));
+
+    let mut array = ["scale_0.3", "scale_1", "scale_0.25", "scale_0.125", "scale_10"];
+
+    array.sort_by(|a, b| fraction.compare(a, b));
+
+    assert_eq!(["scale_0.125", "scale_0.25", "scale_0.3", "scale_1", "scale_10"], array);
+}
+
+#[test]
+fn collator_digit_grouping() {
+    let collator = alphanumeric_sort::Collator::new().digit_grouping(true);
+
+    assert_eq!(
+        Ordering::Less,
+        alphanumeric_sort::compare_str("revenue_1,200.csv", "revenue_980.csv")
+    );
+    assert_eq!(Ordering::Greater, collator.compare("revenue_1,200.csv", "revenue_980.csv"));
+    assert_eq!(Ordering::Less, collator.compare("revenue_1,200.csv", "revenue_1,201.csv"));
+    assert_eq!(Ordering::Less, collator.compare("revenue_999,999.csv", "revenue_1,000,000.csv"));
+    assert_eq!(Ordering::Greater, collator.compare("revenue_1_200.csv", "revenue_980.csv"));
+    assert_eq!(Ordering::Greater, collator.compare("revenue_1'200.csv", "revenue_980.csv"));
+    assert_eq!(Ordering::Greater, collator.compare("revenue_1\u{2009}200.csv", "revenue_980.csv"));
+
+    // ill-formed groupings
+    assert_eq!(Ordering::Less, collator.compare("1,2345", "980"));
+    assert_eq!(Ordering::Less, collator.compare("1,20", "980"));
+    assert_eq!(Ordering::Less, collator.compare("1234,567", "1235"));
+    assert_eq!(Ordering::Less, collator.compare("1,200_000", "1,200,001"));
+
+    // the same values
+    assert_eq!(Ordering::Less, collator.compare("1,200", "1200"));
+    assert_eq!(Ordering::Equal, collator.leading_zeros(false).compare("1,200", "1200"));
+
+    // with decimal fractions
+    let fraction = collator.decimal_mode(alphanumeric_sort::DecimalMode::Fraction);
+
+    assert_eq!(Ordering::Less, fraction.compare("1,200.25", "1,200.3"));
+}
+
+#[test]
+fn collator_hexadecimal() {
+    let collator = alphanumeric_sort::Collator::new().hexadecimal(true);
+
+    assert_eq!(Ordering::Less, alphanumeric_sort::compare_str("dump_0x1f.bin", "dump_0xa.bin"));
+    assert_eq!(Ordering::Greater, collator.compare("dump_0x1f.bin", "dump_0xa.bin"));
+    assert_eq!(Ordering::Greater, collator.compare("dump_0x1f.bin", "dump_30.bin"));
+    assert_eq!(Ordering::Less, collator.compare("dump_0x1f.bin", "dump_32.bin"));
+    assert_eq!(Ordering::Less, collator.compare("dump_0xA.bin", "dump_0x1F.bin"));
+
+    // not hexadecimal numbers
+    assert_eq!(Ordering::Less, collator.compare("dump_0xg.bin", "dump_1.bin"));
+    assert_eq!(Ordering::Less, collator.compare("dump_1f.bin", "dump_2.bin"));
+
+    // the same values
+    assert_eq!(Ordering::Less, collator.compare("0x1f", "0x01f"));
+    assert_eq!(Ordering::Less, collator.compare("0x1F", "0x1f"));
+    assert_eq!(Ordering::Equal, collator.leading_zeros(false).compare("0x1f", "0X001F"));
+
+    // beyond u128
+    assert_eq!(
+        Ordering::Greater,
+        collator
+            .compare("0x100000000000000000000000000000000", "0xffffffffffffffffffffffffffffffff")
+    );
+    assert_eq!(
+        Ordering::Less,
+        collator
+            .compare("0x100000000000000000000000000000000", "0x100000000000000000000000000000001")
+    );
+
+    // 0x100000000000000000000000000000000 is 2^128
+    assert_eq!(
+        Ordering::Greater,
+        collator.compare(
+            "0x100000000000000000000000000000000",
+            "340282366920938463463374607431768211455"
+        )
+    );
+    assert_eq!(
+        Ordering::Less,
+        collator.compare(
+            "0x100000000000000000000000000000000",
+            "340282366920938463463374607431768211457"
+        )
+    );
+    assert_eq!(
+        Ordering::Less,
+        collator.compare(
+            "0x100000000000000000000000000000000",
+            "1000000000000000000000000000000000000000"
+        )
+    );
+    assert_eq!(
+        Ordering::Greater,
+        collator.compare(
+            "0xfffffffffffffffffffffffffffffffff",
+            "1000000000000000000000000000000000000000"
+        )
+    );
+
+    let mut array = ["dump_0x1f.bin", "dump_0xa.bin", "dump_0x2.bin", "dump_0x100.bin"];
+
+    array.sort_by(|a, b| collator.compare(a, b));
+
+    assert_eq!(["dump_0x2.bin", "dump_0xa.bin", "dump_0x1f.bin", "dump_0x100.bin"], array);
+}
+
+#[test]
+fn compare_version() {
+    // the example in the SemVer specification
+    let versions = [
+        "1.0.0-alpha",
+        "1.0.0-alpha.1",
+        "1.0.0-alpha.beta",
+        "1.0.0-beta",
+        "1.0.0-beta.2",
+        "1.0.0-beta.11",
+        "1.0.0-rc.1",
+        "1.0.0",
+        "1.9.0",
+        "1.10.0",
+        "1.11.0",
+        "2.0.0",
+        "2.1.0",
+        "2.1.1",
+    ];
+
+    for (i, a) in versions.iter().enumerate() {
+        for (j, b) in versions.iter().enumerate() {
+            assert_eq!(i.cmp(&j), alphanumeric_sort::compare_version(a, b), "{} vs {}", a, b);
+        }
+    }
+
+    // build metadata
+    assert_eq!(
+        Ordering::Equal,
+        alphanumeric_sort::compare_version("1.0.0+20130313144700", "1.0.0")
+    );
+    assert_eq!(Ordering::Equal, alphanumeric_sort::compare_version("1.0.0+a", "1.0.0+b"));
+    assert_eq!(
+        Ordering::Less,
+        alphanumeric_sort::compare_version("1.0.0-beta+exp.sha.5114f85", "1.0.0-rc.1")
+    );
+
+    // missing numbers and leading zeros
+    assert_eq!(Ordering::Equal, alphanumeric_sort::compare_version("1.2", "1.2.0"));
+    assert_eq!(Ordering::Equal, alphanumeric_sort::compare_version("1.02.0", "1.2.0"));
+    assert_eq!(Ordering::Less, alphanumeric_sort::compare_version("1.2", "1.2.1"));
+    assert_eq!(Ordering::Less, alphanumeric_sort::compare_version("1.2.3", "1.2.3.1"));
+
+    // prefixes and tails
+    assert_eq!(Ordering::Less, alphanumeric_sort::compare_version("v1.0.0-rc.1", "v1.0.0"));
+    assert_eq!(Ordering::Less, alphanumeric_sort::compare_version("1.0.0", "v0.1.0"));
+    assert_eq!(Ordering::Less, alphanumeric_sort::compare_version("app", "app-0.1.0"));
+    assert_eq!(
+        Ordering::Less,
+        alphanumeric_sort::compare_version("app-1.0.0-rc.1.tar.gz", "app-1.0.0.tar.gz")
+    );
+    assert_eq!(
+        Ordering::Less,
+        alphanumeric_sort::compare_version("app-1.0.0_x86.zip", "app-1.0.0_x86_64.zip")
+    );
+    assert_eq!(
+        Ordering::Greater,
+        alphanumeric_sort::compare_version("app-1.0.0-.zip", "app-1.0.0-rc.zip")
+    );
+}
+
+#[test]
+fn sort_version_slice() {
+    let mut array = ["1.0.0", "1.0.0-rc.1+b", "1.0.0-alpha", "1.0.0-rc.1+a", "0.9.10", "0.9.9"];
+
+    alphanumeric_sort::sort_version_slice(&mut array);
+
+    assert_eq!(["0.9.9", "0.9.10", "1.0.0-alpha", "1.0.0-rc.1+b", "1.0.0-rc.1+a", "1.0.0"], array);
+
+    alphanumeric_sort::sort_version_slice_rev(&mut array);
+
+    assert_eq!(["1.0.0", "1.0.0-rc.1+b", "1.0.0-rc.1+a", "1.0.0-alpha", "0.9.10", "0.9.9"], array);
+}
+
+#[test]
+fn sort_slice_by_version_key() {
+    let mut array = [("beta", "v2.0.0-beta"), ("stable", "v2.0.0"), ("old", "v1.12.3")];
+
+    alphanumeric_sort::sort_slice_by_version_key(&mut array, |e| e.1);
+
+    assert_eq!([("old", "v1.12.3"), ("beta", "v2.0.0-beta"), ("stable", "v2.0.0")], array);
+
+    alphanumeric_sort::sort_slice_rev_by_version_key(&mut array, |e| e.1);
+
+    assert_eq!([("stable", "v2.0.0"), ("beta", "v2.0.0-beta"), ("old", "v1.12.3")], array);
+}
+
+#[test]
+fn compare_debian_version() {
+    // (a, b, expected), taken from the dpkg test suite and the Debian Policy Manual
+    let table = [
+        ("1.0", "1.0", Ordering::Equal),
+        ("1.0", "2.0", Ordering::Less),
+        ("1.0", "0:1.0", Ordering::Equal),
+        ("1.0", "1.0-0", Ordering::Equal),
+        ("0:1.0-0", "1.0", Ordering::Equal),
+        ("1:0.9", "2.0", Ordering::Greater),
+        ("1:1.0", "2:0.1", Ordering::Less),
+        ("10:1.0", "9:2.0", Ordering::Greater),
+        ("1.0-1", "1.0-2", Ordering::Less),
+        ("1.0-1", "1.0", Ordering::Greater),
+        ("1.0-2", "1.0-10", Ordering::Less),
+        ("1.0-1", "1.0-1~bpo1", Ordering::Greater),
+        ("1.0~rc1", "1.0", Ordering::Less),
+        ("1.0~rc1", "1.0~rc2", Ordering::Less),
+        ("1.0~~", "1.0~~a", Ordering::Less),
+        ("1.0~~a", "1.0~", Ordering::Less),
+        ("1.0~", "1.0", Ordering::Less),
+        ("1.0", "1.0a", Ordering::Less),
+        ("1.0a", "1.0+", Ordering::Less),
+        ("1.0a", "1.0.1", Ordering::Less),
+        ("1.0+", "1.0.", Ordering::Less),
+        ("1.0.1", "1.0-1", Ordering::Greater),
+        ("1.2.3", "1.2.10", Ordering::Less),
+        ("001", "1", Ordering::Equal),
+        ("1.01", "1.1", Ordering::Equal),
+        ("1.0-a-1", "1.0-b-1", Ordering::Less),
+        ("1.0-a-2", "1.0-a-1", Ordering::Greater),
+        ("2.4.7-1ubuntu1", "2.4.7-1", Ordering::Greater),
+        ("2.4.7-1ubuntu1", "2.4.7-2", Ordering::Less),
+        ("1.0+dfsg-1", "1.0-1", Ordering::Greater),
+        ("1:1.0", "1:1.0 ", Ordering::Equal),
+    ];
+
+    for (a, b, expected) in table.iter() {
+        assert_eq!(*expected, alphanumeric_sort::compare_debian_version(a, b), "{} vs {}", a, b);
+        assert_eq!(
+            expected.reverse(),
+            alphanumeric_sort::compare_debian_version(b, a),
+            "{} vs {}",
+            b,
+            a
+        );
+    }
+}
+
+#[test]
+fn compare_rpm_version() {
+    // (a, b, expected), taken from the rpmvercmp test suite of RPM
+    let table = [
+        ("1.0", "1.0", Ordering::Equal),
+        ("1.0", "2.0", Ordering::Less),
+        ("2.0.1", "2.0.1", Ordering::Equal),
+        ("2.0", "2.0.1", Ordering::Less),
+        ("2.0.1a", "2.0.1", Ordering::Greater),
+        ("5.5p1", "5.5p2", Ordering::Less),
+        ("5.5p10", "5.5p1", Ordering::Greater),
+        ("10xyz", "10.1xyz", Ordering::Less),
+        ("xyz10", "xyz10.1", Ordering::Less),
+        ("xyz.4", "8", Ordering::Less),
+        ("xyz.4", "2", Ordering::Less),
+        ("5.5p2", "5.6p1", Ordering::Less),
+        ("5.6p1", "6.5p1", Ordering::Less),
+        ("6.0.rc1", "6.0", Ordering::Greater),
+        ("10b2", "10a1", Ordering::Greater),
+        ("10a2", "10b2", Ordering::Less),
+        ("1.0a", "1.0aa", Ordering::Less),
+        ("10.0001", "10.1", Ordering::Equal),
+        ("10.0001", "10.0039", Ordering::Less),
+        ("4.999.9", "5.0", Ordering::Less),
+        ("20101121", "20101122", Ordering::Less),
+        ("2_0", "2.0", Ordering::Equal),
+        ("a+", "a_", Ordering::Equal),
+        ("+a", "_a", Ordering::Equal),
+        ("_+", "+_", Ordering::Equal),
+        ("_+", "_", Ordering::Equal),
+        ("+", "_", Ordering::Equal),
+        ("1.0~rc1", "1.0", Ordering::Less),
+        ("1.0~rc1", "1.0~rc2", Ordering::Less),
+        ("1.0~rc1~git123", "1.0~rc1", Ordering::Less),
+        ("1.0^", "1.0", Ordering::Greater),
+        ("1.0^git1", "1.0", Ordering::Greater),
+        ("1.0^git1", "1.0^git2", Ordering::Less),
+        ("1.0^git1", "1.01", Ordering::Less),
+        ("1.0^20160101", "1.0.1", Ordering::Less),
+        ("1.0^20160102", "1.0^20160101^git1", Ordering::Greater),
+        ("1.0~rc1^git1", "1.0~rc1", Ordering::Greater),
+        ("1.0^git1~pre", "1.0^git1", Ordering::Less),
+        // epochs and releases
+        ("1:1.0", "2.0", Ordering::Greater),
+        ("0:1.0", "1.0", Ordering::Equal),
+        ("1.0-1", "1.0-2", Ordering::Less),
+        ("1.0-10", "1.0-9", Ordering::Greater),
+        ("1.0-1", "1.0", Ordering::Equal),
+        ("1.0-1.fc38", "1.0-1.el9", Ordering::Greater),
+        ("2:1.0-1", "1:2.0-1", Ordering::Greater),
+    ];
+
+    for (a, b, expected) in table.iter() {
+        assert_eq!(*expected, alphanumeric_sort::compare_rpm_version(a, b), "{} vs {}", a, b);
+        assert_eq!(
+            expected.reverse(),
+            alphanumeric_sort::compare_rpm_version(b, a),
+            "{} vs {}",
+            b,
+            a
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn compare_path_components() {
+    assert_eq!(Ordering::Greater, alphanumeric_sort::compare_path("a/b", "a-1/x"));
+    assert_eq!(Ordering::Less, alphanumeric_sort::compare_path_components("a/b", "a-1/x"));
+
+    assert_eq!(Ordering::Less, alphanumeric_sort::compare_path_components("a/2/z", "a/10/a"));
+    assert_eq!(Ordering::Less, alphanumeric_sort::compare_path_components("a", "a/b"));
+    assert_eq!(Ordering::Less, alphanumeric_sort::compare_path_components("a/b", "a.b"));
+    assert_eq!(Ordering::Equal, alphanumeric_sort::compare_path_components("a//b/", "a/./b"));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn sort_path_slice_by_components() {
+    use std::path::Path;
+
+    let mut array = [
+        Path::new("shot-1/b"),
+        Path::new("shot/10"),
+        Path::new("shot-1"),
+        Path::new("shot/2"),
+        Path::new("shot"),
+    ];
+
+    alphanumeric_sort::sort_path_slice_by_components(&mut array);
+
+    assert_eq!(
+        [
+            Path::new("shot"),
+            Path::new("shot/2"),
+            Path::new("shot/10"),
+            Path::new("shot-1"),
+            Path::new("shot-1/b"),
+        ],
+        array
+    );
+
+    alphanumeric_sort::sort_path_slice_rev_by_components(&mut array);
+
+    assert_eq!(
+        [
+            Path::new("shot-1/b"),
+            Path::new("shot-1"),
+            Path::new("shot/10"),
+            Path::new("shot/2"),
+            Path::new("shot"),
+        ],
+        array
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn sort_slice_by_path_components_key() {
+    use std::path::Path;
+
+    let mut array = [(1, Path::new("a-1/x")), (2, Path::new("a/b")), (3, Path::new("a/b"))];
+
+    alphanumeric_sort::sort_slice_by_path_components_key(&mut array, |e| e.1);
+
+    assert_eq!([(2, Path::new("a/b")), (3, Path::new("a/b")), (1, Path::new("a-1/x"))], array);
+
+    alphanumeric_sort::sort_slice_rev_by_path_components_key(&mut array, |e| e.1);
+
+    assert_eq!([(1, Path::new("a-1/x")), (2, Path::new("a/b")), (3, Path::new("a/b"))], array);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn sort_path_slice_dirs_first() {
+    use std::path::Path;
+
+    let mut array = [
+        Path::new("shot-10.png"),
+        Path::new("shot-10"),
+        Path::new("shot-2.png"),
+        Path::new("shot-2"),
+        Path::new("shot-1.png"),
+    ];
+
+    alphanumeric_sort::sort_path_slice_dirs_first(&mut array, |p| p.extension().is_none());
+
+    assert_eq!(
+        [
+            Path::new("shot-2"),
+            Path::new("shot-10"),
+            Path::new("shot-1.png"),
+            Path::new("shot-2.png"),
+            Path::new("shot-10.png"),
+        ],
+        array
+    );
+
+    alphanumeric_sort::sort_path_slice_files_first(&mut array, |p| p.extension().is_none());
+
+    assert_eq!(
+        [
+            Path::new("shot-1.png"),
+            Path::new("shot-2.png"),
+            Path::new("shot-10.png"),
+            Path::new("shot-2"),
+            Path::new("shot-10"),
+        ],
+        array
+    );
+}
+
+#[cfg(all(feature = "std", unix))]
+#[test]
+fn sort_path_slice_files_first_non_utf8() {
+    use std::{ffi::OsStr, os::unix::ffi::OsStrExt, path::Path};
+
+    let p = |s: &'static [u8]| Path::new(OsStr::from_bytes(s));
+
+    // `is_dir` is called once for each element, even if a key is not valid UTF-8
+    let calls = std::cell::Cell::new(0);
+
+    let mut array = [p(b"b-2\xe4"), p(b"a-2"), p(b"b-1"), p(b"a-1\xff"), p(b"c")];
+
+    alphanumeric_sort::sort_path_slice_files_first(&mut array, |p| {
+        calls.set(calls.get() + 1);
+
+        p.as_os_str().as_bytes().starts_with(b"a")
+    });
+
+    assert_eq!(5, calls.get());
+    assert_eq!([p(b"b-1"), p(b"b-2\xe4"), p(b"c"), p(b"a-1\xff"), p(b"a-2")], array);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn sort_slice_by_path_key_dirs_first() {
+    use std::{fs, path::PathBuf};
+
+    let root = std::env::temp_dir().join("alphanumeric-sort-dirs-first");
+
+    let _ = fs::remove_dir_all(&root);
+
+    fs::create_dir_all(root.join("dir-10")).unwrap();
+    fs::create_dir_all(root.join("dir-9")).unwrap();
+    fs::write(root.join("file-2"), "").unwrap();
+    fs::write(root.join("file-11"), "").unwrap();
+
+    let mut entries: Vec<(PathBuf, fs::FileType)> = fs::read_dir(&root)
+        .unwrap()
+        .map(|e| {
+            let e = e.unwrap();
+
+            (PathBuf::from(e.file_name()), e.file_type().unwrap())
+        })
+        .collect();
+
+    fs::remove_dir_all(&root).unwrap();
+
+    alphanumeric_sort::sort_slice_by_path_key_dirs_first(&mut entries, |e| &e.0, |e| e.1);
+
+    assert_eq!(
+        ["dir-9", "dir-10", "file-2", "file-11"],
+        entries.iter().map(|e| e.0.to_str().unwrap()).collect::<Vec<_>>().as_slice()
+    );
+
+    alphanumeric_sort::sort_slice_by_path_key_files_first(&mut entries, |e| &e.0, |e| e.1);
+
+    assert_eq!(
+        ["file-2", "file-11", "dir-9", "dir-10"],
+        entries.iter().map(|e| e.0.to_str().unwrap()).collect::<Vec<_>>().as_slice()
+    );
+}
+
+#[test]
+fn collator_extension_mode() {
+    use alphanumeric_sort::{Collator, ExtensionMode};
+
+    let collator = Collator::new().extension_mode(ExtensionMode::StemFirst);
+
+    assert_eq!(Ordering::Greater, alphanumeric_sort::compare_str("a.txt", "a-b.md"));
+    assert_eq!(Ordering::Less, collator.compare("a.txt", "a-b.md"));
+    assert_eq!(Ordering::Less, collator.compare("a.md", "a.txt"));
+    assert_eq!(Ordering::Less, collator.compare("a.txt", "a1.txt"));
+    assert_eq!(Ordering::Less, collator.compare("a", "a.txt"));
+    assert_eq!(Ordering::Less, collator.compare("a", "a."));
+    assert_eq!(Ordering::Less, collator.compare("shot-2.png", "shot-10.jpg"));
+
+    // the file name is after the last '/', and a leading dot does not start an extension
+    assert_eq!(Ordering::Less, collator.compare("docs.v2/a.txt", "docs.v2/a-b.md"));
+    assert_eq!(Ordering::Less, collator.compare(".bashrc", ".bashrc.bak"));
+
+    let mut array = ["a1.txt", "a.txt", "a.md", "b.md"];
+
+    array.sort_by(|a, b| collator.compare(a, b));
+
+    assert_eq!(["a.md", "a.txt", "a1.txt", "b.md"], array);
+
+    let collator = Collator::new().extension_mode(ExtensionMode::ExtensionFirst);
+
+    assert_eq!(Ordering::Greater, collator.compare("a.txt", "b.md"));
+    assert_eq!(Ordering::Less, collator.compare("Makefile", "a.md"));
+
+    array.sort_by(|a, b| collator.compare(a, b));
+
+    assert_eq!(["a.md", "b.md", "a.txt", "a1.txt"], array);
+}
+
+#[test]
+fn collator_extension_policy() {
+    use alphanumeric_sort::{Collator, ExtensionMode, ExtensionPolicy, COMPOUND_EXTENSIONS};
+
+    let collator = Collator::new().extension_mode(ExtensionMode::ExtensionFirst);
+
+    // "gz" vs "txt"
+    assert_eq!(Ordering::Less, collator.compare("report.10.tar.gz", "report.9.txt"));
+    // "tar.gz" vs "tar.gz"
+    assert_eq!(Ordering::Less, collator.compare("report.9.tar.gz", "report.10.tar.gz"));
+    // "10.tar.gz" vs "9.txt"
+    assert_eq!(
+        Ordering::Greater,
+        collator.extension_policy(ExtensionPolicy::All).compare("report.10.tar.gz", "report.9.txt")
+    );
+    // "txt" vs "md", even if the file names start with non-ASCII characters
+    assert_eq!(
+        Ordering::Greater,
+        collator.extension_policy(ExtensionPolicy::All).compare("中.txt", "a.md")
+    );
+    assert_eq!(
+        Ordering::Less,
+        collator.extension_policy(ExtensionPolicy::All).compare("中.tar.gz", "a.txt")
+    );
+
+    let collator = collator.extension_policy(ExtensionPolicy::Known(COMPOUND_EXTENSIONS));
+
+    // "tar.gz" vs "gz"
+    assert_eq!(Ordering::Greater, collator.compare("report.10.tar.gz", "report.9.gz"));
+    assert_eq!(Ordering::Less, collator.compare("report.9.TAR.GZ", "report.10.tar.gz"));
+    // "gz" vs "tar.gz", because a multi-part extension needs a stem
+    assert_eq!(Ordering::Less, collator.compare("tar.gz", "a.tar.gz"));
+
+    let mut array = ["app-1.10.zip", "app-1.9.tar.gz", "app-1.10.tar.gz", "app-1.9.zip"];
+
+    array.sort_by(|a, b| collator.compare(a, b));
+
+    assert_eq!(["app-1.9.tar.gz", "app-1.10.tar.gz", "app-1.9.zip", "app-1.10.zip"], array);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn compare_c_str_non_utf8() {
+    use std::ffi::CStr;
+
+    let c = |s: &'static [u8]| CStr::from_bytes_with_nul(s).unwrap();
+
+    assert_eq!(
+        Ordering::Less,
+        alphanumeric_sort::compare_c_str(c(b"shot-2\xff\0"), c(b"shot-10\0"))
+    );
+    assert_eq!(
+        Ordering::Less,
+        alphanumeric_sort::compare_c_str(c(b"shot-2\xff\0"), c(b"shot-10\xff\0"))
+    );
+    assert_eq!(
+        Ordering::Greater,
+        alphanumeric_sort::compare_c_str(c(b"a\xff\0"), c(b"a\xc3\xa9\0"))
+    );
+    assert_eq!(Ordering::Less, alphanumeric_sort::compare_c_str(c(b"a\xc3\0"), c(b"a\xff\0")));
+    assert_eq!(Ordering::Equal, alphanumeric_sort::compare_c_str(c(b"a\xff\0"), c(b"a\xff\0")));
+
+    let mut array = [c(b"shot-10\0"), c(b"shot-2\xff\0"), c(b"shot-1\0"), c(b"shot-2\0")];
+
+    alphanumeric_sort::sort_c_str_slice(&mut array);
+
+    assert_eq!([c(b"shot-1\0"), c(b"shot-2\0"), c(b"shot-2\xff\0"), c(b"shot-10\0")], array);
+}
+
+#[cfg(all(feature = "std", unix))]
+#[test]
+fn sort_path_slice_non_utf8() {
+    use std::{ffi::OsStr, os::unix::ffi::OsStrExt, path::Path};
+
+    let p = |s: &'static [u8]| Path::new(OsStr::from_bytes(s));
+
+    let mut array = [p(b"shot-11"), p(b"shot-2\xe4"), p(b"shot-1"), p(b"\xff"), p(b"shot-10")];
+
+    alphanumeric_sort::sort_path_slice(&mut array);
+
+    assert_eq!([p(b"shot-1"), p(b"shot-2\xe4"), p(b"shot-10"), p(b"shot-11"), p(b"\xff")], array);
+
+    let mut array = [(1, p(b"b-2\xe4")), (2, p(b"a-10")), (3, p(b"b-1"))];
+
+    alphanumeric_sort::sort_slice_by_path_key_dirs_first(&mut array, |e| e.1, |e| e.0 != 2);
+
+    assert_eq!([(3, p(b"b-1")), (1, p(b"b-2\xe4")), (2, p(b"a-10"))], array);
+}
+
+#[test]
+fn compare_bytes() {
+    assert_eq!(Ordering::Less, alphanumeric_sort::compare_bytes(b"shot-2", b"shot-10"));
+    assert_eq!(
+        alphanumeric_sort::compare_str("第1章", "第1-2章"),
+        alphanumeric_sort::compare_bytes("第1章", "第1-2章")
+    );
+
+    // invalid UTF-8
+    assert_eq!(Ordering::Less, alphanumeric_sort::compare_bytes(b"shot-2\xff", b"shot-10"));
+    assert_eq!(Ordering::Less, alphanumeric_sort::compare_bytes(b"shot-02\xff", b"shot-2\xff\xff"));
+    assert_eq!(Ordering::Greater, alphanumeric_sort::compare_bytes(b"a\xff", "a中"));
+    assert_eq!(Ordering::Less, alphanumeric_sort::compare_bytes(b"a\xe4\xb8", b"a\xe5"));
+    assert_eq!(Ordering::Equal, alphanumeric_sort::compare_bytes(b"a\xff1", b"a\xff1"));
+}
+
+#[test]
+fn sort_bytes_slice() {
+    let mut array: [&[u8]; 4] = [b"shot-11", b"shot-2\xff", b"shot-1", b"\xff"];
+
+    alphanumeric_sort::sort_bytes_slice(&mut array);
+
+    assert_eq!([&b"shot-1"[..], b"shot-2\xff", b"shot-11", b"\xff"], array);
+
+    alphanumeric_sort::sort_bytes_slice_rev(&mut array);
+
+    assert_eq!([&b"\xff"[..], b"shot-11", b"shot-2\xff", b"shot-1"], array);
+}
+
+#[test]
+fn sort_slice_by_bytes_key() {
+    let mut array = [(1, b"a-10".to_vec()), (2, b"a-9\xfe".to_vec()), (3, b"a-10".to_vec())];
+
+    alphanumeric_sort::sort_slice_by_bytes_key(&mut array, |e| &e.1);
+
+    assert_eq!([2, 1, 3], [array[0].0, array[1].0, array[2].0]);
+
+    alphanumeric_sort::sort_slice_rev_by_bytes_key(&mut array, |e| &e.1);
+
+    assert_eq!([1, 3, 2], [array[0].0, array[1].0, array[2].0]);
+
+    alphanumeric_sort::sort_slice_unstable_by_bytes_key(&mut array, |e| &e.1);
+
+    assert_eq!(2, array[0].0);
+
+    alphanumeric_sort::sort_slice_rev_unstable_by_bytes_key(&mut array, |e| &e.1);
+
+    assert_eq!(2, array[2].0);
+}
+
+#[test]
+fn compare_utf16() {
+    let u = |s: &str| s.encode_utf16().collect::<Vec<u16>>();
+
+    for (a, b) in [
+        ("shot-2", "shot-10"),
+        ("第1章", "第1-2章"),
+        ("1", "中"),
+        ("0001", "001"),
+        ("a😀", "a\u{ffff}"),
+        ("a😀1", "a😀01"),
+    ]
+    .iter()
+    {
+        assert_eq!(
+            alphanumeric_sort::compare_str(a, b),
+            alphanumeric_sort::compare_utf16(u(a), u(b)),
+            "{} vs {}",
+            a,
+            b
+        );
+    }
+
+    // unpaired surrogates
+    assert_eq!(Ordering::Less, alphanumeric_sort::compare_utf16([0x61, 0xD800], u("a\u{e000}")));
+    assert_eq!(Ordering::Greater, alphanumeric_sort::compare_utf16([0x61, 0xD800], u("a\u{d7ff}")));
+    assert_eq!(Ordering::Less, alphanumeric_sort::compare_utf16([0xDC00, 0xD800], [0xDC01]));
+    assert_eq!(
+        Ordering::Less,
+        alphanumeric_sort::compare_utf16([0xD800, 0x32], [0xD800, 0x31, 0x30])
+    );
+}
+
+#[test]
+fn sort_utf16_slice() {
+    let u = |s: &str| s.encode_utf16().collect::<Vec<u16>>();
+
+    let mut array = [u("shot-11"), u("shot-2"), u("shot-1"), u("shot-😀")];
+
+    alphanumeric_sort::sort_utf16_slice(&mut array);
+
+    assert_eq!([u("shot-1"), u("shot-2"), u("shot-11"), u("shot-😀")], array);
+
+    alphanumeric_sort::sort_utf16_slice_rev(&mut array);
+
+    assert_eq!([u("shot-😀"), u("shot-11"), u("shot-2"), u("shot-1")], array);
+
+    let mut array = [(1, u("a-10")), (2, u("a-9")), (3, u("a-10"))];
+
+    alphanumeric_sort::sort_slice_by_utf16_key(&mut array, |e| &e.1);
+
+    assert_eq!([2, 1, 3], [array[0].0, array[1].0, array[2].0]);
+
+    alphanumeric_sort::sort_slice_rev_by_utf16_key(&mut array, |e| &e.1);
+
+    assert_eq!([1, 3, 2], [array[0].0, array[1].0, array[2].0]);
+}
+
+#[test]
+fn alphanumeric_str() {
+    use std::{
+        collections::{hash_map::DefaultHasher, BTreeMap},
+        hash::{Hash, Hasher},
+    };
+
+    use alphanumeric_sort::{AlphanumericStr, AlphanumericString};
+
+    let hash = |v: &dyn Fn(&mut DefaultHasher)| {
+        let mut hasher = DefaultHasher::new();
+
+        v(&mut hasher);
+
+        hasher.finish()
+    };
+
+    assert!(AlphanumericStr::new("shot-2") < AlphanumericStr::new("shot-10"));
+    assert_ne!(AlphanumericStr::new("01"), AlphanumericStr::new("1"));
+    assert_eq!(Ordering::Greater, AlphanumericStr::new("01").cmp(AlphanumericStr::new("1")));
+    assert_eq!(
+        hash(&|h| AlphanumericStr::new("shot-1").hash(h)),
+        hash(&|h| AlphanumericString::from("shot-1").hash(h))
+    );
+
+    let mut map = BTreeMap::new();
+
+    map.insert(AlphanumericString::from("shot-10"), 10);
+    map.insert(AlphanumericString::from("shot-2"), 2);
+    map.insert(AlphanumericString::from("shot-02"), 20);
+    map.insert(AlphanumericString::from("shot-1"), 1);
+
+    assert_eq!(Some(&2), map.get(AlphanumericStr::new("shot-2")));
+    assert_eq!(Some(&20), map.get(AlphanumericStr::new("shot-02")));
+    assert_eq!(
+        ["shot-1", "shot-2", "shot-02", "shot-10"],
+        map.keys().map(|k| k.as_str()).collect::<Vec<_>>().as_slice()
+    );
+
+    let s: &AlphanumericStr = "shot-1".into();
+    let owned: AlphanumericString = s.to_owned();
+
+    assert_eq!(s, &*owned);
+    assert_eq!("shot-1", String::from(owned));
+}
+
+#[test]
+fn alphanumeric() {
+    use std::collections::{BTreeSet, BinaryHeap, HashSet};
+
+    use alphanumeric_sort::Alphanumeric;
+
+    let mut array = [
+        Alphanumeric(String::from("shot-10")),
+        Alphanumeric(String::from("shot-2")),
+        Alphanumeric(String::from("shot-1")),
+    ];
+
+    array.sort();
+
+    assert_eq!(Ok(1), array.binary_search(&Alphanumeric(String::from("shot-2"))));
+    assert_eq!(Err(2), array.binary_search(&Alphanumeric(String::from("shot-3"))));
+
+    let set: BTreeSet<_> =
+        ["shot-1", "shot-01", "shot-10", "shot-2"].iter().map(Alphanumeric).collect();
+
+    assert_eq!(
+        ["shot-1", "shot-01", "shot-2", "shot-10"],
+        set.into_iter().map(|s| *s.into_inner()).collect::<Vec<_>>().as_slice()
+    );
+
+    let set: HashSet<_> = ["shot-1", "shot-01", "shot-1"].iter().map(Alphanumeric).collect();
+
+    assert_eq!(2, set.len());
+
+    let mut heap: BinaryHeap<_> =
+        ["shot-2", "shot-10", "shot-1"].iter().map(Alphanumeric).collect();
+
+    assert_eq!("shot-10", *heap.pop().unwrap().into_inner());
+    assert_eq!("shot-2", *heap.pop().unwrap().into_inner());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn alphanumeric_path() {
+    use std::{
+        collections::{BTreeMap, HashSet},
+        path::{Path, PathBuf},
+    };
+
+    use alphanumeric_sort::{AlphanumericPath, AlphanumericPathBuf};
+
+    assert!(AlphanumericPath::new("shot-2") < AlphanumericPath::new("shot-10"));
+    assert_ne!(AlphanumericPath::new("shot-01"), AlphanumericPath::new("shot-1"));
+    // stricter than `Path`
+    assert_eq!(Path::new("a/b"), Path::new("a//b"));
+    assert_ne!(AlphanumericPath::new("a/b"), AlphanumericPath::new("a//b"));
+
+    let mut map = BTreeMap::new();
+
+    for (i, p) in ["第10章", "第1-2章", "第1章", "第2章"].iter().enumerate() {
+        map.insert(AlphanumericPathBuf::from(*p), i);
+    }
+
+    assert_eq!(Some(&3), map.get(AlphanumericPath::new(Path::new("第2章"))));
+    assert_eq!(None, map.get(AlphanumericPath::new(Path::new("第3章"))));
+    assert_eq!(
+        [Path::new("第1章"), Path::new("第1-2章"), Path::new("第2章"), Path::new("第10章")],
+        map.keys().map(|k| k.as_path()).collect::<Vec<_>>().as_slice()
+    );
+
+    let set: HashSet<AlphanumericPathBuf> =
+        ["a/b", "a//b", "a/b"].iter().map(|p| AlphanumericPathBuf::from(*p)).collect();
+
+    assert_eq!(2, set.len());
+    assert!(set.contains(AlphanumericPath::new("a//b")));
+
+    let p: &AlphanumericPath = Path::new("shot-1").into();
+
+    assert_eq!(PathBuf::from("shot-1"), PathBuf::from(p.to_owned()));
+    assert_eq!(Some("shot-1"), p.to_str());
+}
+
+#[test]
+fn sort_key() {
+    let cases = [
+        "",
+        "\0",
+        "/",
+        "0",
+        "00",
+        "1",
+        "01",
+        "001",
+        "1a",
+        "1a01",
+        "01a1",
+        "10",
+        "99999999999999999999",
+        "100000000000000000000",
+        ":",
+        "a",
+        "a1",
+        "a1b",
+        "a01b",
+        "a1-",
+        "a1ÿ",
+        "a1Ā",
+        "a1中",
+        "aÿ",
+        "aĀ",
+        "第1章",
+        "第1-2章",
+        "第10章",
+        "中",
+    ];
+
+    for a in cases {
+        for b in cases {
+            assert_eq!(
+                alphanumeric_sort::compare_str(a, b),
+                alphanumeric_sort::sort_key(a).cmp(&alphanumeric_sort::sort_key(b)),
+                "{:?} {:?}",
+                a,
+                b
+            );
+        }
+    }
+
+    let mut buffer = b"prefix".to_vec();
+
+    alphanumeric_sort::encode_into("shot-01", &mut buffer);
+
+    assert_eq!(b"prefix", &buffer[..6]);
+    assert_eq!(alphanumeric_sort::sort_key("shot-01"), &buffer[6..]);
+}
+
+#[test]
+fn sort_str_slice_cached() {
+    let mut names = [
+        "第10-15-2章",
+        "第1-2章",
+        "第2-4章",
+        "第2-33章",
+        "第1章",
+        "第1-4章",
+        "第2-3章",
+        "第1-11章",
+        "第10-1章",
+        "第3-1章",
+        "第2-10章",
+        "第2-2章",
+        "第1-3章",
+        "第10-15章",
+        "第10-2章",
+        "第10-15-1章",
+        "第2-1章",
+        "第2-12章",
+        "第1-10章",
+        "第3-10章",
+        "第01章",
+        "第001章",
+        "第1章",
+    ];
+
+    let mut expected = names;
+
+    alphanumeric_sort::sort_str_slice(&mut expected);
+    alphanumeric_sort::sort_str_slice_cached(&mut names);
+
+    assert_eq!(expected, names);
+
+    let mut empty: [&str; 0] = [];
+
+    alphanumeric_sort::sort_str_slice_cached(&mut empty);
+}
+
+#[test]
+fn chunks() {
+    use alphanumeric_sort::Chunk;
+
+    assert_eq!(None, alphanumeric_sort::chunks("").next());
+
+    assert_eq!(
+        vec![
+            Chunk::Text("第"),
+            Chunk::Number {
+                digits: "1", value_digits: "1", leading_zeros: 0
+            },
+            Chunk::Text("-"),
+            Chunk::Number {
+                digits: "000", value_digits: "", leading_zeros: 3
+            },
+            Chunk::Text("章 ١"),
+            Chunk::Number {
+                digits: "0012", value_digits: "12", leading_zeros: 2
+            },
+        ],
+        alphanumeric_sort::chunks("第1-000章 ١0012").collect::<Vec<_>>()
+    );
+
+    // a renaming tool incrementing the number
+    let renamed: String = alphanumeric_sort::chunks("shot-009.png")
+        .map(|chunk| match chunk {
+            Chunk::Number {
+                digits, ..
+            } => format!("{:0width$}", digits.parse::<u64>().unwrap() + 1, width = digits.len()),
+            _ => chunk.as_str().to_string(),
+        })
+        .collect();
+
+    assert_eq!("shot-010.png", renamed);
+    assert_eq!(Ordering::Less, alphanumeric_sort::compare_str("shot-009.png", &renamed));
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_sort_str_slice() {
+    let names: Vec<String> = (0..10000).map(|i| format!("shot-{}", (i * 7919) % 10007)).collect();
+
+    let mut expected = names.clone();
+    let mut actual = names.clone();
+
+    alphanumeric_sort::sort_str_slice(&mut expected);
+    alphanumeric_sort::par_sort_str_slice(&mut actual);
+
+    assert_eq!(expected, actual);
+
+    let mut actual = names.clone();
+
+    alphanumeric_sort::par_sort_slice_by_str_key(&mut actual, |s| s.as_str());
+
+    assert_eq!(expected, actual);
+
+    alphanumeric_sort::sort_str_slice_rev(&mut expected);
+    alphanumeric_sort::par_sort_slice_rev_by_str_key(&mut actual, |s| s.as_str());
+
+    assert_eq!(expected, actual);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_sort_path_slice() {
+    use std::{path::PathBuf, rc::Rc};
+
+    let paths: Vec<PathBuf> =
+        (0..10000).map(|i| PathBuf::from(format!("第{}章", (i * 7919) % 10007))).collect();
+
+    let mut expected = paths.clone();
+    let mut actual = paths.clone();
+
+    alphanumeric_sort::sort_path_slice(&mut expected);
+    alphanumeric_sort::par_sort_path_slice(&mut actual);
+
+    assert_eq!(expected, actual);
+
+    // neither the elements nor the key function have to be thread-safe
+    let mut actual: Vec<Rc<PathBuf>> = paths.into_iter().map(Rc::new).collect();
+
+    alphanumeric_sort::par_sort_slice_rev_by_path_key(&mut actual, |p| p.as_path());
+    alphanumeric_sort::sort_path_slice_rev(&mut expected);
+
+    assert_eq!(expected, actual.iter().map(|p| p.to_path_buf()).collect::<Vec<_>>());
+}
+
+#[cfg(all(feature = "rayon", unix))]
+#[test]
+fn par_sort_path_slice_non_utf8() {
+    use std::{ffi::OsStr, os::unix::ffi::OsStrExt, path::Path};
+
+    let mut expected = [
+        Path::new(OsStr::from_bytes(b"shot-10\xff")),
+        Path::new("shot-2"),
+        Path::new(OsStr::from_bytes(b"shot-1\xff")),
+        Path::new("shot-1"),
+    ];
+    let mut actual = expected;
+
+    alphanumeric_sort::sort_path_slice(&mut expected);
+    alphanumeric_sort::par_sort_path_slice(&mut actual);
+
+    assert_eq!(expected, actual);
+}
+
+#[cfg(all(feature = "rayon", unix))]
+#[test]
+fn par_sort_os_str_slice_non_utf8() {
+    use std::{ffi::OsString, os::unix::ffi::OsStringExt};
+
+    let names: Vec<OsString> = (0..1000)
+        .map(|i| {
+            let mut name = format!("shot-{}", (i * 7919) % 1009).into_bytes();
+
+            if i % 3 == 0 {
+                name.push(0xFF);
+            }
+
+            OsString::from_vec(name)
+        })
+        .collect();
+
+    let mut expected = names.clone();
+    let mut actual = names.clone();
+
+    alphanumeric_sort::sort_os_str_slice(&mut expected);
+    alphanumeric_sort::par_sort_os_str_slice(&mut actual);
+
+    assert_eq!(expected, actual);
+
+    let mut actual: Vec<(usize, &OsString)> = names.iter().enumerate().collect();
+    let mut expected = actual.clone();
+
+    alphanumeric_sort::sort_slice_rev_by_os_str_key(&mut expected, |e| e.1.as_os_str());
+    alphanumeric_sort::par_sort_slice_rev_by_os_str_key(&mut actual, |e| e.1.as_os_str());
+
+    assert_eq!(expected, actual);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_sort_c_str_slice() {
+    use std::ffi::CString;
+
+    let names: Vec<CString> = (0..1000)
+        .map(|i| {
+            let mut name = format!("shot-{}", (i * 7919) % 1009).into_bytes();
+
+            if i % 3 == 0 {
+                name.push(0xFF);
+            }
+
+            CString::new(name).unwrap()
+        })
+        .collect();
+
+    let mut expected = names.clone();
+    let mut actual = names.clone();
+
+    alphanumeric_sort::sort_c_str_slice(&mut expected);
+    alphanumeric_sort::par_sort_c_str_slice(&mut actual);
+
+    assert_eq!(expected, actual);
+
+    let mut actual: Vec<(usize, &CString)> = names.iter().enumerate().collect();
+    let mut expected = actual.clone();
+
+    alphanumeric_sort::sort_slice_by_c_str_key(&mut expected, |e| e.1.as_c_str());
+    alphanumeric_sort::par_sort_slice_by_c_str_key(&mut actual, |e| e.1.as_c_str());
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn partial_sort_str_slice() {
+    let names: Vec<String> =
+        (0..1000).map(|i| format!("shot-{:0w$}", (i * 7919) % 1009, w = i % 3)).collect();
+
+    let mut sorted = names.clone();
+
+    alphanumeric_sort::sort_str_slice(&mut sorted);
+
+    for k in [0, 1, 20, 999, 1000, 2000] {
+        let mut array = names.clone();
+
+        alphanumeric_sort::partial_sort_str_slice(&mut array, k);
+
+        assert_eq!(sorted[..k.min(1000)], array[..k.min(1000)]);
+
+        let mut array = names.clone();
+
+        let top = alphanumeric_sort::top_k_by_str_key(&mut array, k, |s| s.as_str());
+
+        assert_eq!(k.min(1000), top.len());
+        assert!(top.iter().eq(sorted.iter().rev().take(k)));
+    }
+
+    let mut array = names.clone();
+
+    let (before, nth, after) = alphanumeric_sort::select_nth_str(&mut array, 500);
+
+    assert_eq!(sorted[500], *nth);
+    assert!(before
+        .iter()
+        .all(|s| alphanumeric_sort::compare_str(s, &sorted[500]) == Ordering::Less));
+    assert!(after
+        .iter()
+        .all(|s| alphanumeric_sort::compare_str(s, &sorted[500]) == Ordering::Greater));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn partial_sort_path_slice() {
+    use std::{ffi::OsStr, path::Path};
+
+    let mut array = [
+        Path::new("第10-15-2章"),
+        Path::new("第1-2章"),
+        Path::new("第2-4章"),
+        Path::new("第2-33章"),
+        Path::new("第1章"),
+        Path::new("第1-4章"),
+        Path::new("第2-3章"),
+        Path::new("第1-11章"),
+        Path::new("第10-1章"),
+        Path::new("第3-1章"),
+    ];
+
+    let mut sorted = array;
+
+    alphanumeric_sort::sort_path_slice(&mut sorted);
+
+    alphanumeric_sort::partial_sort_path_slice(&mut array, 3);
+
+    assert_eq!(sorted[..3], array[..3]);
+
+    let top = alphanumeric_sort::top_k_by_path_key(&mut array, 2, |p| *p);
+
+    assert_eq!([Path::new("第10-15-2章"), Path::new("第10-1章")], top);
+
+    let (_, nth, _) = alphanumeric_sort::select_nth_path(&mut array, 5);
+
+    assert_eq!(sorted[5], *nth);
+
+    let mut array = [OsStr::new("shot-2"), OsStr::new("shot-10"), OsStr::new("shot-1")];
+
+    alphanumeric_sort::partial_sort_os_str_slice(&mut array, 1);
+
+    assert_eq!(OsStr::new("shot-1"), array[0]);
+
+    let top = alphanumeric_sort::top_k_by_os_str_key(&mut array, 1, |s| *s);
+
+    assert_eq!([OsStr::new("shot-10")], top);
+
+    let (_, nth, _) = alphanumeric_sort::select_nth_os_str(&mut array, 1);
+
+    assert_eq!(OsStr::new("shot-2"), *nth);
+}
+
+#[cfg(all(feature = "std", unix))]
+#[test]
+fn partial_sort_path_slice_non_utf8() {
+    use std::{ffi::OsStr, os::unix::ffi::OsStrExt, path::Path};
+
+    let mut array = [
+        Path::new(OsStr::from_bytes(b"shot-10\xff")),
+        Path::new("shot-2"),
+        Path::new(OsStr::from_bytes(b"shot-1\xff")),
+        Path::new("shot-1"),
+    ];
+
+    let mut sorted = array;
+
+    alphanumeric_sort::sort_path_slice(&mut sorted);
+
+    alphanumeric_sort::partial_sort_path_slice(&mut array, 3);
+
+    assert_eq!(sorted[..3], array[..3]);
+
+    let top = alphanumeric_sort::top_k_by_path_key(&mut array, 1, |p| *p);
+
+    assert_eq!([Path::new(OsStr::from_bytes(b"shot-10\xff"))], top);
+}
+
+#[test]
+fn binary_search_str() {
+    let mut names: Vec<String> = Vec::new();
+
+    for i in 0..200 {
+        let name = format!("shot-{:0w$}", (i * 37) % 101, w = i % 3);
+
+        let index = alphanumeric_sort::insert_sorted_str(&mut names, name.clone());
+
+        assert_eq!(name, names[index]);
+    }
+
+    let mut sorted = names.clone();
+
+    alphanumeric_sort::sort_str_slice(&mut sorted);
+
+    assert_eq!(sorted, names);
+
+    for (i, name) in names.iter().enumerate() {
+        let index = alphanumeric_sort::binary_search_str(&names, name).unwrap();
+
+        assert_eq!(name, &names[index]);
+        assert_eq!(Ok(index), alphanumeric_sort::binary_search_by_str_key(&names, name, |s| s));
+        assert!(alphanumeric_sort::partition_point_str(&names, name) <= i);
+    }
+
+    assert_eq!(Err(0), alphanumeric_sort::binary_search_str(&names, "shot"));
+    assert_eq!(Err(names.len()), alphanumeric_sort::binary_search_str(&names, "shot-1000"));
+    assert_eq!(0, alphanumeric_sort::partition_point_str(&names, "shot-0"));
+    assert_eq!(
+        alphanumeric_sort::partition_point_str(&names, "shot-51a"),
+        alphanumeric_sort::binary_search_str(&names, "shot-51a").unwrap_err()
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn binary_search_path() {
+    use std::path::{Path, PathBuf};
+
+    let mut paths: Vec<PathBuf> = Vec::new();
+
+    for p in ["第10章", "第1-2章", "第2章", "第1章", "第1-11章"] {
+        alphanumeric_sort::insert_sorted_path(&mut paths, PathBuf::from(p));
+    }
+
+    let mut sorted = paths.clone();
+
+    alphanumeric_sort::sort_path_slice(&mut sorted);
+
+    assert_eq!(sorted, paths);
+
+    assert_eq!(Ok(2), alphanumeric_sort::binary_search_path(&paths, "第1-11章"));
+    assert_eq!(Ok(3), alphanumeric_sort::binary_search_by_path_key(&paths, "第2章", |p| p));
+    assert_eq!(Err(4), alphanumeric_sort::binary_search_path(&paths, Path::new("第3章")));
+    assert_eq!(4, alphanumeric_sort::partition_point_path(&paths, "第3章"));
+}
+
+#[cfg(all(feature = "std", unix))]
+#[test]
+fn binary_search_path_non_utf8() {
+    use std::{ffi::OsStr, os::unix::ffi::OsStrExt, path::Path};
+
+    let mut paths = Vec::new();
+
+    for p in [
+        Path::new(OsStr::from_bytes(b"shot-10\xff")),
+        Path::new("shot-2"),
+        Path::new(OsStr::from_bytes(b"shot-1\xff")),
+        Path::new("shot-1"),
+    ] {
+        alphanumeric_sort::insert_sorted_path(&mut paths, p);
+    }
+
+    let mut sorted = paths.clone();
+
+    alphanumeric_sort::sort_slice_by_os_str_key(&mut sorted, |p| p.as_os_str());
+
+    assert_eq!(sorted, paths);
+
+    for (i, p) in paths.iter().enumerate() {
+        assert_eq!(Ok(i), alphanumeric_sort::binary_search_path(&paths, p));
+    }
+}